@@ -11,10 +11,23 @@ fn parse_take_100(_n: u64) {
     for _time in cron.clone().iter_after(time).take(100) {}
 }
 
+fn every_second_take_1000(cron: &Cron) {
+    let time = Local::now();
+    for _time in cron.iter_after(time).take(1000) {}
+}
+
 pub fn criterion_benchmark(c: &mut Criterion) {
     c.bench_function("parse_take_100", |b| {
         b.iter(|| parse_take_100(black_box(20)))
     });
+
+    let every_second: Cron = Cron::new("* * * * * *")
+        .with_seconds_required()
+        .parse()
+        .expect("Couldn't parse cron string");
+    c.bench_function("every_second_take_1000", |b| {
+        b.iter(|| every_second_take_1000(black_box(&every_second)))
+    });
 }
 
 criterion_group!(benches, criterion_benchmark);
@@ -18,7 +18,7 @@ fn main() {
     // There is also iter_after, which does not match starting time
     println!(
         "Finding matches of pattern '{}' starting from {}:",
-        cron.pattern.to_string(),
+        cron.pattern(),
         time
     );
 
@@ -19,13 +19,13 @@ fn main() {
     println!("Current time is: {}", time);
     println!(
         "Pattern \"{}\" does {} time {}",
-        cron.pattern.to_string(),
+        cron.pattern(),
         if matches { "match" } else { "not match" },
         time
     );
     println!(
         "Pattern \"{}\" will match next time at {}",
-        cron.pattern.to_string(),
+        cron.pattern(),
         next
     );
 
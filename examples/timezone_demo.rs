@@ -17,7 +17,7 @@ fn main() {
     println!("Time in Europe/Stockholm time is: {}", &now_stockholm);
     println!(
         "Pattern \"{}\" will match next time at (Europe/Stockholm): {}",
-        cron.pattern.to_string(),
+        cron.pattern(),
         next_stockholm
     );
 }
@@ -1,4 +1,5 @@
 use crate::errors::CronError;
+use alloc::{format, string::ToString, vec::Vec};
 
 // Constants for flags
 pub const NONE_BIT: u8 = 0;
@@ -18,6 +19,21 @@ pub const CLOSEST_WEEKDAY_BIT: u8 = 1 << 7;
 // Used for last day of month
 pub const LAST_BIT: u8 = 1 << 6;
 
+// The order in which set values of a component are visited
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    Ascending,
+    Descending,
+}
+
+// The error for a position below the input offset, like weekday 0 when 1=SUN
+fn below_input_offset(pos: u8, input_offset: u8) -> CronError {
+    CronError::ComponentError(format!(
+        "Position {} is less than the input offset {}.",
+        pos, input_offset
+    ))
+}
+
 /// Represents a component of a cron pattern, such as minute, hour, or day of week.
 ///
 /// Each `CronComponent` holds information about permissible values (min, max),
@@ -35,12 +51,14 @@ pub const LAST_BIT: u8 = 1 << 6;
 /// // Sets the minute component to trigger at every 15th minute
 #[derive(Debug, Default, Clone)]
 pub struct CronComponent {
-    bitfields: Vec<u8>,   // Vector of u8 to act as multiple bitfields
-    pub min: u8,          // Minimum value this component can take
-    pub max: u8,          // Maximum value this component can take
-    features: u8,         // Single u8 bitfield to indicate supported special bits, like LAST_BIT
+    bitfields: [u64; 8], // One u64 mask per flag bit, bit n of a mask is set if the flag is set at position n
+    pub min: u8,         // Minimum value this component can take
+    pub max: u8,         // Maximum value this component can take
+    features: u8,        // Single u8 bitfield to indicate supported special bits, like LAST_BIT
     enabled_features: u8, // Bitfield to hold component-wide special bits like LAST_BIT
     input_offset: u8, // Offset for numerical representation of weekdays. normally 0=SUN,1=MON etc, setting this to 1 makes 1=SUN...
+    wrap_after: Option<u8>, // The value after which ranges like 22-2 wrap around to min, None if they may not wrap
+    single_start_steps: bool, // Whether steps like 15/5 may start from a single value instead of a range
 }
 
 impl CronComponent {
@@ -60,9 +78,10 @@ impl CronComponent {
     /// Returns a new instance of `CronComponent`.
     pub fn new(min: u8, max: u8, features: u8, input_offset: u8) -> Self {
         Self {
-            // Vector of u8 to act as multiple bitfields.
-            // - Initialized with NONE_BIT for each element.
-            bitfields: vec![NONE_BIT; (max + 1) as usize],
+            // One u64 mask per flag bit, indexed by the flag's bit number.
+            // - Positions are limited to 0-63, which covers all cron fields
+            // - Initialized with no positions set
+            bitfields: [0; 8],
 
             // Minimum value this component can take.
             // - Example: 0 for the minute-field
@@ -82,18 +101,109 @@ impl CronComponent {
 
             // Offset for numerical representation of weekdays. normally 0=SUN,1=MON etc, setting this to 1 makes 1=SUN...
             input_offset,
+
+            // The value after which ranges like 22-2 wrap around to min.
+            // - Disabled by default, enabled for cyclic fields using `with_wrapping`
+            wrap_after: None,
+
+            // Whether steps like 15/5 may start from a single value, running up to max.
+            // - Disabled by default, enabled for Quartz expressions using `with_single_start_steps`
+            single_start_steps: false,
         }
     }
 
-    // Set a bit at a given position (0 to 59)
-    pub fn set_bit(&mut self, mut pos: u8, bit: u8) -> Result<(), CronError> {
-        if pos < self.input_offset {
-            return Err(CronError::ComponentError(format!(
-                "Position {} is less than the input offset {}.",
-                pos, self.input_offset
-            )));
+    // Allow ranges to wrap around from max to min, for cyclic fields like hours
+    pub fn with_wrapping(self) -> Self {
+        let max = self.max;
+        self.with_wrapping_after(max)
+    }
+
+    // Allow ranges to wrap around from last to min. Values above last are aliases of the values
+    // from min on, like weekday 7 for Sunday (0), and count as those when wrapping
+    pub fn with_wrapping_after(mut self, last: u8) -> Self {
+        self.wrap_after = Some(last);
+        self
+    }
+
+    // Allow steps from a single start value, like 15/5 running from 15 up to max, as in Quartz
+    pub fn with_single_start_steps(mut self) -> Self {
+        self.single_start_steps = true;
+        self
+    }
+
+    // Returns the range of values accepted in a pattern. Values are stored shifted down by the
+    // input offset, but still have to fit min-max as written, so the offset only raises the low end
+    pub fn input_range(&self) -> (u8, u8) {
+        (self.min.saturating_add(self.input_offset), self.max)
+    }
+
+    // The error for a value outside the accepted range, naming that range
+    fn out_of_range(&self, what: &str) -> CronError {
+        let (low, high) = self.input_range();
+        CronError::ComponentError(format!("{} out of range {}-{}.", what, low, high))
+    }
+
+    // Combines the masks of all flags in bit, ALL_BIT is by far the most common and checked first
+    fn mask(&self, bit: u8) -> u64 {
+        if bit == ALL_BIT {
+            return self.bitfields[0];
         }
-        pos -= self.input_offset;
+        (0..8)
+            .filter(|flag| bit & (1 << flag) != 0)
+            .fold(0, |mask, flag| mask | self.bitfields[flag])
+    }
+
+    // Returns the positions within min-max where the given bit is set, in ascending order.
+    // Only set positions are visited, so sparse fields are cheap regardless of their range
+    pub fn get_set_values(&self, bit: u8) -> Vec<u8> {
+        let mut values = Vec::with_capacity(self.count_set_values(bit) as usize);
+        values.extend(self.iter_matching(bit, Direction::Ascending));
+        values
+    }
+
+    // Walks the positions where the given bit is set in either direction, without allocating
+    pub fn iter_matching(&self, bit: u8, direction: Direction) -> impl Iterator<Item = u8> {
+        let mut mask = self.mask(bit);
+        core::iter::from_fn(move || {
+            if mask == 0 {
+                return None;
+            }
+            let position = match direction {
+                Direction::Ascending => mask.trailing_zeros(),
+                Direction::Descending => u64::BITS - 1 - mask.leading_zeros(),
+            };
+            mask &= !(1 << position); // Clear the visited position
+            Some(position as u8)
+        })
+    }
+
+    // Returns the number of positions where the given bit is set, without allocating
+    pub fn count_set_values(&self, bit: u8) -> u32 {
+        self.mask(bit).count_ones()
+    }
+
+    // Returns true if exactly one value is set in this component, like the hour of "0 12 * * *".
+    // Only plain values count, special bits like L or # are not considered
+    pub fn is_single_fixed(&self) -> bool {
+        self.count_set_values(ALL_BIT) == 1
+    }
+
+    // Parses a single entry on a cleared copy of this component, returning the resulting bitfields.
+    // Used to inspect what an individual list entry contributes to the field.
+    pub fn entry_bits(&self, entry: &str) -> Result<[u64; 8], CronError> {
+        let mut component = self.clone();
+        component.bitfields = [0; 8];
+        component.enabled_features = 0;
+        component.parse(entry)?;
+        Ok(component.bitfields)
+    }
+
+    // Set a bit at a given position (0 to 59)
+    pub fn set_bit(&mut self, pos: u8, bit: u8) -> Result<(), CronError> {
+        // Guard the subtraction, values below the input offset (like weekday 0 when 1=SUN) are invalid
+        let pos = pos
+            .checked_sub(self.input_offset)
+            .ok_or_else(|| below_input_offset(pos, self.input_offset))?;
         if pos < self.min || pos > self.max {
             return Err(CronError::ComponentError(format!(
                 "Position {} is out of bounds for the current range ({}-{}).",
@@ -106,27 +216,26 @@ impl CronComponent {
                 bit, self.features
             )));
         }
-        let index = pos as usize; // Convert the position to an index
-        if index >= self.bitfields.len() {
-            // In case the index is somehow out of the vector's bounds
+        if pos >= 64 {
+            // In case the position is somehow out of the masks' bounds
             return Err(CronError::ComponentError(format!(
-                "Position {} is out of the bitfields vector's bounds.",
+                "Position {} is out of the bitfield masks' bounds.",
                 pos
             )));
         }
-        self.bitfields[index] |= bit; // Set the specific bit at the position
+        // Set the position in the mask of each flag in bit
+        for flag in (0..8).filter(|flag| bit & (1 << flag) != 0) {
+            self.bitfields[flag] |= 1 << pos;
+        }
         Ok(())
     }
 
     // Unset a specific bit at a given position
-    pub fn unset_bit(&mut self, mut pos: u8, bit: u8) -> Result<(), CronError> {
-        if pos < self.input_offset {
-            return Err(CronError::ComponentError(format!(
-                "Position {} is less than the input offset {}.",
-                pos, self.input_offset
-            )));
-        }
-        pos -= self.input_offset;
+    pub fn unset_bit(&mut self, pos: u8, bit: u8) -> Result<(), CronError> {
+        // Guard the subtraction, values below the input offset (like weekday 0 when 1=SUN) are invalid
+        let pos = pos
+            .checked_sub(self.input_offset)
+            .ok_or_else(|| below_input_offset(pos, self.input_offset))?;
         if pos < self.min || pos > self.max {
             return Err(CronError::ComponentError(format!(
                 "Position {} is out of bounds for the current range ({}-{}).",
@@ -139,15 +248,17 @@ impl CronComponent {
                 bit, self.features
             )));
         }
-        let index = pos as usize; // Convert the position to an index
-        if index >= self.bitfields.len() {
-            // In case the index is somehow out of the vector's bounds
+        if pos >= 64 {
+            // In case the position is somehow out of the masks' bounds
             return Err(CronError::ComponentError(format!(
-                "Position {} is out of the bitfields vector's bounds.",
+                "Position {} is out of the bitfield masks' bounds.",
                 pos
             )));
         }
-        self.bitfields[index] &= !bit; // Unset the specific bit at the position
+        // Unset the position in the mask of each flag in bit
+        for flag in (0..8).filter(|flag| bit & (1 << flag) != 0) {
+            self.bitfields[flag] &= !(1 << pos);
+        }
         Ok(())
     }
 
@@ -163,16 +274,13 @@ impl CronComponent {
                 "Bit 0b{:08b} is not supported by the current features 0b{:08b}.",
                 bit, self.features
             )))
+        } else if pos >= 64 {
+            Err(CronError::ComponentError(format!(
+                "Position {} is out of the bitfield masks' bounds.",
+                pos
+            )))
         } else {
-            let index = pos as usize;
-            if index >= self.bitfields.len() {
-                Err(CronError::ComponentError(format!(
-                    "Position {} is out of the bitfields vector's bounds.",
-                    pos
-                )))
-            } else {
-                Ok((self.bitfields[index] & bit) != 0)
-            }
+            Ok(self.mask(bit) & (1 << pos) != 0)
         }
     }
 
@@ -233,6 +341,8 @@ impl CronComponent {
         }
 
         for part in field.split(',') {
+            // Empty parts from leading, trailing or doubled commas are skipped, so
+            // "1,2," and ",1,,2" are both treated as "1,2"
             let trimmed_part = part.trim();
             if trimmed_part.is_empty() {
                 continue;
@@ -242,6 +352,8 @@ impl CronComponent {
 
             if parsed_part.contains('/') {
                 self.handle_stepping(&parsed_part)?;
+            } else if parsed_part.starts_with('l') && parsed_part.contains('-') {
+                self.handle_last_offset(&parsed_part)?;
             } else if parsed_part.contains('-') {
                 self.handle_range(&parsed_part)?;
             } else if parsed_part.contains('w') {
@@ -253,6 +365,16 @@ impl CronComponent {
                 // Replace 'l' with 'L'
                 parsed_part = parsed_part.replace('l', "L");
 
+                // 'L' and an nth specifier like "#2" contradict each other, reject "5L#2" and "5#2L"
+                // rather than silently ignoring one of them
+                if let Some((_, nth)) = parsed_part.split_once('#') {
+                    if parsed_part.contains('L') && nth != "L" {
+                        return Err(CronError::ComponentError(
+                            "L can not be combined with an nth specifier #.".to_string(),
+                        ));
+                    }
+                }
+
                 // If 'L' is contained without '#', like "5L", add the missing '#'
                 if parsed_part.ends_with('L') && !parsed_part.contains('#') {
                     parsed_part = parsed_part.replace('L', "#L");
@@ -291,7 +413,7 @@ impl CronComponent {
 
             if nth == 0 || nth > 5 {
                 Err(CronError::ComponentError(
-                    "Nth specifier out of bounds.".to_string(),
+                    "Nth specifier out of range 1-5.".to_string(),
                 ))
             } else {
                 match nth {
@@ -316,6 +438,12 @@ impl CronComponent {
     }
 
     fn handle_closest_weekday(&mut self, value: &str) -> Result<(), CronError> {
+        // The Quartz form "LW", the last weekday of the month, has no flag to map onto
+        if value == "lw" {
+            return Err(CronError::ComponentError(
+                "LW (last weekday of the month) is not supported.".to_string(),
+            ));
+        }
         if let Some(day_pos) = value.find('w') {
             // Use a slice
             let day_str = &value[..day_pos];
@@ -327,9 +455,7 @@ impl CronComponent {
 
             // Check if the day is within the allowed range
             if day < self.min || day > self.max {
-                return Err(CronError::ComponentError(
-                    "Day for closest weekday out of bounds.".to_string(),
-                ));
+                return Err(self.out_of_range("Day for closest weekday"));
             }
 
             // Set the bit for the closest weekday
@@ -341,8 +467,37 @@ impl CronComponent {
         Ok(())
     }
 
+    // Handles an offset from the last day of the month, like 'L-3'. The offset is stored
+    // as LAST_BIT at the position of the offset, so 'L-3' sets LAST_BIT at position 3.
+    fn handle_last_offset(&mut self, value: &str) -> Result<(), CronError> {
+        // Only the day-of-month field supports W, and likewise L-n
+        if !self.is_feature_allowed(CLOSEST_WEEKDAY_BIT) {
+            return Err(CronError::ComponentError(
+                "L-n not allowed in the current field.".to_string(),
+            ));
+        }
+
+        let offset = value[2..]
+            .parse::<u8>()
+            .map_err(|_| CronError::ComponentError("Invalid offset for L-n.".to_string()))?;
+
+        // The shortest month has 28 days, so larger offsets would fall before the 1st
+        if offset == 0 || offset > 27 {
+            return Err(CronError::ComponentError(
+                "Offset for L-n must be between 1 and 27.".to_string(),
+            ));
+        }
+
+        self.set_bit(offset, LAST_BIT)
+    }
+
     fn handle_range(&mut self, range: &str) -> Result<(), CronError> {
         let bit_to_set = CronComponent::get_nth_bit(range)?;
+        if bit_to_set != ALL_BIT && !self.is_feature_allowed(NTH_ALL) {
+            return Err(CronError::ComponentError(
+                "Nth specifier not allowed in the current field.".to_string(),
+            ));
+        }
         let str_clean = CronComponent::strip_nth_part(range);
 
         let parts: Vec<&str> = str_clean.split('-').map(str::trim).collect();
@@ -359,18 +514,43 @@ impl CronComponent {
             .parse::<u8>()
             .map_err(|_| CronError::ComponentError("Invalid end of range.".to_string()))?;
 
-        if start > end || start < self.min || end > self.max {
-            return Err(CronError::ComponentError(
-                "Range out of bounds.".to_string(),
-            ));
-        }
-
-        for value in start..=end {
+        for value in self.range_values(start, end)? {
             self.set_bit(value, bit_to_set)?;
         }
         Ok(())
     }
 
+    // Returns the values covered by a range, in order. A range where start is greater than end
+    // wraps around from max to min if the component allows it, so 22-2 yields 22, 23, 0, 1, 2.
+    fn range_values(&self, start: u8, end: u8) -> Result<Vec<u8>, CronError> {
+        if start < self.min || start > self.max || end < self.min || end > self.max {
+            return Err(self.out_of_range("Range"));
+        }
+        if start <= end {
+            Ok((start..=end).collect())
+        } else if let Some(last) = self.wrap_after {
+            // The lowest accepted input is min, shifted by the input offset
+            let wrap_start = self.min.max(self.input_offset);
+            let unalias = |value: u8| {
+                if value > last {
+                    value - (last + 1 - wrap_start)
+                } else {
+                    value
+                }
+            };
+            let (start, end) = (unalias(start), unalias(end));
+            if start <= end {
+                Ok((start..=end).collect())
+            } else {
+                Ok((start..=last).chain(wrap_start..=end).collect())
+            }
+        } else {
+            Err(CronError::ComponentError(
+                "Range start is greater than range end.".to_string(),
+            ))
+        }
+    }
+
     fn handle_number(&mut self, value: &str) -> Result<(), CronError> {
         let bit_to_set = CronComponent::get_nth_bit(value)?;
         let value_clean = CronComponent::strip_nth_part(value);
@@ -378,9 +558,7 @@ impl CronComponent {
             .parse::<u8>()
             .map_err(|_| CronError::ComponentError("Invalid number.".to_string()))?;
         if num < self.min || num > self.max {
-            return Err(CronError::ComponentError(
-                "Number out of bounds.".to_string(),
-            ));
+            return Err(self.out_of_range("Number"));
         }
 
         self.set_bit(num, bit_to_set)?;
@@ -389,6 +567,11 @@ impl CronComponent {
 
     pub fn handle_stepping(&mut self, stepped_range: &str) -> Result<(), CronError> {
         let bit_to_set = CronComponent::get_nth_bit(stepped_range)?;
+        if bit_to_set != ALL_BIT && !self.is_feature_allowed(NTH_ALL) {
+            return Err(CronError::ComponentError(
+                "Nth specifier not allowed in the current field.".to_string(),
+            ));
+        }
         let stepped_range_clean = CronComponent::strip_nth_part(stepped_range);
 
         let parts: Vec<&str> = stepped_range_clean.split('/').collect();
@@ -409,8 +592,18 @@ impl CronComponent {
             ));
         }
 
+        // A step beyond the number of values in the field can only ever reach its first value
+        let (low, high) = (self.min.max(self.input_offset), self.max);
+        if u16::from(step) > u16::from(high - low) + 1 {
+            return Err(CronError::ComponentError(format!(
+                "Step {} is larger than the range of the field ({}-{}).",
+                step, low, high
+            )));
+        }
+
         let (start, end) = if range_part == "*" {
-            (self.min, self.max)
+            // The lowest accepted input is min, shifted by the input offset
+            (self.min.max(self.input_offset), self.max)
         } else if range_part.contains('-') {
             let bounds: Vec<&str> = range_part.split('-').collect();
             if bounds.len() != 2 {
@@ -430,23 +623,23 @@ impl CronComponent {
             let single_start = range_part
                 .parse::<u8>()
                 .map_err(|_| CronError::ComponentError("Invalid start.".to_string()))?;
+            if !self.single_start_steps {
+                return Err(CronError::ComponentError(format!(
+                    "Step needs a range or *, like {}-{}/{}.",
+                    single_start, self.max, step
+                )));
+            }
             // If only one number is provided, set the range to go from the start value to the max value.
             (single_start, self.max)
         };
 
-        if start < self.min || end > self.max || start > end {
-            return Err(CronError::ComponentError(
-                "Range is out of bounds in stepping.".to_string(),
-            ));
-        }
-
         // Apply stepping within the range
-        let mut value = start;
-        while value <= end {
+        for value in self
+            .range_values(start, end)?
+            .into_iter()
+            .step_by(step as usize)
+        {
             self.set_bit(value, bit_to_set)?;
-            value = value.checked_add(step).ok_or_else(|| {
-                CronError::ComponentError("Value exceeded max after stepping.".to_string())
-            })?;
         }
 
         Ok(())
@@ -463,8 +656,8 @@ mod tests {
         let component = CronComponent::new(0, 59, ALL_BIT | LAST_BIT, 0);
         assert_eq!(component.min, 0);
         assert_eq!(component.max, 59);
-        // Ensure all bitfields are initialized to NONE_BIT
-        assert!(component.bitfields.iter().all(|&b| b == NONE_BIT));
+        // Ensure all bitfields are initialized to no positions set
+        assert!(component.bitfields.iter().all(|&mask| mask == 0));
         // Check that ALL_BIT and LAST_BIT are included in features
         assert!(component.features & (ALL_BIT | LAST_BIT) == (ALL_BIT | LAST_BIT));
     }
@@ -528,6 +721,159 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_parse_wrapping_range() {
+        let mut component = CronComponent::new(0, 23, ALL_BIT, 0).with_wrapping();
+        component.parse("22-2").unwrap();
+        for i in 0..=23 {
+            assert_eq!(
+                component.is_bit_set(i, ALL_BIT).unwrap(),
+                [22, 23, 0, 1, 2].contains(&i)
+            );
+        }
+
+        let mut component = CronComponent::new(0, 23, ALL_BIT, 0).with_wrapping();
+        component.parse("21-3/2").unwrap();
+        for i in 0..=23 {
+            assert_eq!(
+                component.is_bit_set(i, ALL_BIT).unwrap(),
+                [21, 23, 1, 3].contains(&i)
+            );
+        }
+
+        // Weekday 7 is an alias of Sunday, which is only counted once when wrapping
+        let weekdays = |range: &str| {
+            let mut component = CronComponent::new(0, 7, ALL_BIT, 0).with_wrapping_after(6);
+            component.parse(range).unwrap();
+            (0..=7)
+                .filter(|&i| component.is_bit_set(i, ALL_BIT).unwrap())
+                .collect::<Vec<u8>>()
+        };
+        assert_eq!(weekdays("5-2"), [0, 1, 2, 5, 6]);
+        assert_eq!(weekdays("5-2/2"), [0, 2, 5]);
+        assert_eq!(weekdays("7-2"), [0, 1, 2]);
+        assert_eq!(weekdays("6-7"), [6, 7]);
+
+        // Wrapping is not allowed unless enabled
+        let mut component = CronComponent::new(1, 31, ALL_BIT, 0);
+        assert!(component.parse("28-2").is_err());
+        assert!(component.parse("28-2/2").is_err());
+    }
+
+    #[test]
+    fn test_get_set_values_sparse_and_full() {
+        let mut component = CronComponent::new(0, 59, ALL_BIT | LAST_BIT, 0);
+        component.parse("0,7,59").unwrap();
+        assert_eq!(component.get_set_values(ALL_BIT), vec![0, 7, 59]);
+        assert_eq!(component.count_set_values(ALL_BIT), 3);
+        assert!(component.get_set_values(LAST_BIT).is_empty());
+        assert_eq!(component.count_set_values(LAST_BIT), 0);
+
+        component.parse("*").unwrap();
+        assert_eq!(
+            component.get_set_values(ALL_BIT),
+            (0..=59).collect::<Vec<u8>>()
+        );
+        assert_eq!(component.count_set_values(ALL_BIT), 60);
+    }
+
+    #[test]
+    fn test_is_single_fixed() {
+        let single_fixed = |entry: &str| {
+            let mut component = CronComponent::new(0, 23, ALL_BIT | LAST_BIT, 0);
+            component.parse(entry).unwrap();
+            component.is_single_fixed()
+        };
+        assert!(single_fixed("12"));
+        assert!(single_fixed("12-12"));
+        assert!(single_fixed("20-23/10"));
+        assert!(!single_fixed("*"));
+        assert!(!single_fixed("1,2"));
+        assert!(!single_fixed("*/12"));
+        assert!(!single_fixed("L"));
+
+        // An unparsed component has no values set
+        assert!(!CronComponent::new(0, 23, ALL_BIT, 0).is_single_fixed());
+    }
+
+    #[test]
+    fn test_iter_matching() {
+        let mut component = CronComponent::new(0, 59, ALL_BIT, 0);
+        component.parse("5-20/5,42,59").unwrap();
+        let ascending: Vec<u8> = component
+            .iter_matching(ALL_BIT, Direction::Ascending)
+            .collect();
+        let descending: Vec<u8> = component
+            .iter_matching(ALL_BIT, Direction::Descending)
+            .collect();
+        assert_eq!(ascending, vec![5, 10, 15, 20, 42, 59]);
+        assert_eq!(descending, vec![59, 42, 20, 15, 10, 5]);
+
+        // Both ends of the mask are reachable
+        let mut wide = CronComponent::new(0, 63, ALL_BIT, 0);
+        wide.parse("0,63").unwrap();
+        let descending: Vec<u8> = wide.iter_matching(ALL_BIT, Direction::Descending).collect();
+        assert_eq!(descending, vec![63, 0]);
+
+        // Flags are walked separately, and an empty flag yields nothing
+        let mut days_of_week = CronComponent::new(0, 7, LAST_BIT | NTH_ALL, 0);
+        days_of_week.parse("1,5#2,0L").unwrap();
+        assert_eq!(
+            days_of_week
+                .iter_matching(NTH_2ND_BIT, Direction::Ascending)
+                .collect::<Vec<u8>>(),
+            vec![5]
+        );
+        assert_eq!(
+            days_of_week
+                .iter_matching(NTH_1ST_BIT, Direction::Descending)
+                .next(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_entry_bits() {
+        let mut component = CronComponent::new(0, 59, ALL_BIT, 0);
+        component.parse("1-5").unwrap();
+        let bits = component.entry_bits("4-6").unwrap();
+        let all = bits[0]; // The mask of ALL_BIT
+        assert_eq!(all & (1 << 3), 0);
+        assert_ne!(all & (1 << 4), 0);
+        assert_ne!(all & (1 << 6), 0);
+        // The component itself is left untouched
+        assert!(component.is_bit_set(1, ALL_BIT).unwrap());
+        assert!(!component.is_bit_set(6, ALL_BIT).unwrap());
+    }
+
+    #[test]
+    fn test_values_below_input_offset_fail() {
+        let mut component = CronComponent::new(0, 7, LAST_BIT | NTH_ALL, 1);
+        assert!(matches!(
+            component.set_bit(0, ALL_BIT),
+            Err(CronError::ComponentError(_))
+        ));
+        assert!(matches!(
+            component.unset_bit(0, ALL_BIT),
+            Err(CronError::ComponentError(_))
+        ));
+        assert!(component.set_bit(8, ALL_BIT).is_ok());
+        assert!(component.set_bit(9, ALL_BIT).is_err());
+        for value in ["0", "0-3", "0/2", "0#2", "0l", "-1"] {
+            assert!(
+                component.parse(value).is_err(),
+                "{value} should be rejected"
+            );
+        }
+
+        // Stepping from * starts at the lowest accepted input
+        let mut component = CronComponent::new(0, 7, LAST_BIT | NTH_ALL, 1);
+        component.parse("*/2").unwrap();
+        for i in 0..=6 {
+            assert_eq!(component.is_bit_set(i, ALL_BIT).unwrap(), i % 2 == 0);
+        }
+    }
+
     #[test]
     fn test_parse_stepping() {
         let mut component = CronComponent::new(0, 59, ALL_BIT, 0);
@@ -554,6 +900,39 @@ mod tests {
         assert!(component.parse("60").is_err()); // out of bounds for the minute field
     }
 
+    #[test]
+    fn test_parse_last_offset() {
+        let mut component = CronComponent::new(1, 31, LAST_BIT | CLOSEST_WEEKDAY_BIT, 0);
+        component.parse("l-3").unwrap();
+        assert!(component.is_bit_set(3, LAST_BIT).unwrap());
+        assert!(!component.is_bit_set(3, ALL_BIT).unwrap());
+        assert!(!component.is_feature_enabled(LAST_BIT));
+
+        assert!(component.parse("l-0").is_err());
+        assert!(component.parse("l-28").is_err());
+        assert!(component.parse("l-").is_err());
+        assert!(component.parse("1-5l").is_err());
+
+        // Only allowed in the day-of-month field
+        let mut component = CronComponent::new(0, 7, LAST_BIT | NTH_ALL, 0);
+        assert!(component.parse("l-3").is_err());
+    }
+
+    #[test]
+    fn test_parse_last_combined_with_nth_fails() {
+        for value in ["5l#2", "5#2l", "5#l#2", "l#2"] {
+            let mut component = CronComponent::new(0, 7, LAST_BIT | NTH_ALL, 0);
+            assert!(
+                matches!(component.parse(value), Err(CronError::ComponentError(_))),
+                "{value} should be rejected"
+            );
+        }
+
+        let mut component = CronComponent::new(0, 7, LAST_BIT | NTH_ALL, 0);
+        assert!(component.parse("5l").is_ok());
+        assert!(component.parse("5#2").is_ok());
+    }
+
     #[test]
     fn test_parse_closest_weekday() {
         let mut component = CronComponent::new(1, 31, CLOSEST_WEEKDAY_BIT, 0);
@@ -0,0 +1,321 @@
+//! Type level parser configurations.
+//!
+//! Parser options are normally set on a [`Cron`] with methods like `with_seconds_required`.
+//! In places where a method can not be called, such as when deserializing, the options can
+//! instead be described by a type implementing [`ParserConfig`].
+//!
+//! With the `serde` feature enabled, [`CronWith`] deserializes a [`Cron`] using the parser
+//! configuration given as type parameter, and [`deserialize_with_config`] can be used with
+//! `#[serde(deserialize_with = "...")]` on a plain `Cron` field. For the common configurations,
+//! modules like `seconds_optional` can be used with `#[serde(with = "...")]` directly, for example
+//! `#[serde(with = "croner::config::seconds_optional")]` to accept 6-field patterns.
+//!
+//! ```rust
+//! use croner::config::{CronWith, SecondsRequired};
+//!
+//! // A struct deriving Deserialize could use `CronWith<SecondsRequired>` as field type,
+//! // or `#[serde(deserialize_with = "croner::config::deserialize_with_config::<_, SecondsRequired>")]`
+//! // on a `Cron` field.
+//! let _schedule: Option<CronWith<SecondsRequired>> = None;
+//! ```
+
+use crate::Cron;
+use core::marker::PhantomData;
+use core::ops::Deref;
+
+#[cfg(feature = "serde")]
+use alloc::string::String;
+#[cfg(feature = "serde")]
+use serde::{de, Deserialize, Serialize, Serializer};
+
+/// Describes a parser configuration at the type level.
+pub trait ParserConfig {
+    /// Applies the configuration to an unparsed `Cron`.
+    fn configure(cron: &mut Cron);
+}
+
+/// Parser configuration with the default options.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DefaultConfig;
+
+/// Parser configuration allowing an optional seconds field, see `Cron::with_seconds_optional`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SecondsOptional;
+
+/// Parser configuration requiring a seconds field, see `Cron::with_seconds_required`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SecondsRequired;
+
+/// Parser configuration using Quartz-style weekdays, see `Cron::with_alternative_weekdays`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct AlternativeWeekdays;
+
+/// Parser configuration combining day of month and day of week, see `Cron::with_dom_and_dow`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DomAndDow;
+
+/// Parser configuration for Quartz expressions, see `Cron::with_quartz`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Quartz;
+
+impl ParserConfig for DefaultConfig {
+    fn configure(_cron: &mut Cron) {}
+}
+
+impl ParserConfig for SecondsOptional {
+    fn configure(cron: &mut Cron) {
+        cron.with_seconds_optional();
+    }
+}
+
+impl ParserConfig for SecondsRequired {
+    fn configure(cron: &mut Cron) {
+        cron.with_seconds_required();
+    }
+}
+
+impl ParserConfig for AlternativeWeekdays {
+    fn configure(cron: &mut Cron) {
+        cron.with_alternative_weekdays();
+    }
+}
+
+impl ParserConfig for DomAndDow {
+    fn configure(cron: &mut Cron) {
+        cron.with_dom_and_dow();
+    }
+}
+
+impl ParserConfig for Quartz {
+    fn configure(cron: &mut Cron) {
+        cron.with_quartz();
+    }
+}
+
+// Combinations of configurations are expressed as tuples, like (SecondsRequired, DomAndDow)
+impl<A: ParserConfig, B: ParserConfig> ParserConfig for (A, B) {
+    fn configure(cron: &mut Cron) {
+        A::configure(cron);
+        B::configure(cron);
+    }
+}
+
+impl<A: ParserConfig, B: ParserConfig, C: ParserConfig> ParserConfig for (A, B, C) {
+    fn configure(cron: &mut Cron) {
+        A::configure(cron);
+        B::configure(cron);
+        C::configure(cron);
+    }
+}
+
+/// A `Cron` parsed with the parser configuration `C`.
+///
+/// Dereferences to the inner `Cron`, and (de)serializes as a pattern string when the
+/// `serde` feature is enabled.
+#[derive(Debug, Clone)]
+pub struct CronWith<C: ParserConfig> {
+    cron: Cron,
+    config: PhantomData<C>,
+}
+
+impl<C: ParserConfig> CronWith<C> {
+    /// Parses `pattern` using the parser configuration `C`.
+    pub fn parse(pattern: &str) -> Result<Self, crate::errors::CronError> {
+        let mut cron = Cron::new(pattern);
+        C::configure(&mut cron);
+        Ok(Self {
+            cron: cron.parse()?,
+            config: PhantomData,
+        })
+    }
+
+    /// Returns the inner `Cron`.
+    pub fn into_inner(self) -> Cron {
+        self.cron
+    }
+}
+
+impl<C: ParserConfig> Deref for CronWith<C> {
+    type Target = Cron;
+
+    fn deref(&self) -> &Cron {
+        &self.cron
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<C: ParserConfig> Serialize for CronWith<C> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.cron.serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, C: ParserConfig> Deserialize<'de> for CronWith<C> {
+    fn deserialize<D>(deserializer: D) -> Result<CronWith<C>, D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        let pattern = String::deserialize(deserializer)?;
+        CronWith::parse(&pattern).map_err(de::Error::custom)
+    }
+}
+
+/// Deserializes a `Cron` using the parser configuration `C`, for use with
+/// `#[serde(deserialize_with = "croner::config::deserialize_with_config::<_, SecondsRequired>")]`.
+#[cfg(feature = "serde")]
+pub fn deserialize_with_config<'de, D, C>(deserializer: D) -> Result<Cron, D::Error>
+where
+    D: de::Deserializer<'de>,
+    C: ParserConfig,
+{
+    CronWith::<C>::deserialize(deserializer).map(CronWith::into_inner)
+}
+
+// Generates a module with serialize and deserialize functions for a configuration, for use
+// with #[serde(with = "...")]
+#[cfg(feature = "serde")]
+macro_rules! serde_with_module {
+    ($module:ident, $config:ty, $doc:literal) => {
+        #[doc = $doc]
+        pub mod $module {
+            use crate::Cron;
+            use serde::{Deserializer, Serialize, Serializer};
+
+            /// Serializes the `Cron` as its pattern string.
+            pub fn serialize<S>(cron: &Cron, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+            {
+                cron.serialize(serializer)
+            }
+
+            /// Deserializes a pattern string using this module's parser configuration.
+            pub fn deserialize<'de, D>(deserializer: D) -> Result<Cron, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                super::deserialize_with_config::<D, $config>(deserializer)
+            }
+        }
+    };
+}
+
+#[cfg(feature = "serde")]
+serde_with_module!(
+    seconds_optional,
+    super::SecondsOptional,
+    "(De)serializes a `Cron` allowing an optional seconds field, for use with \
+     `#[serde(with = \"croner::config::seconds_optional\")]`."
+);
+
+#[cfg(feature = "serde")]
+serde_with_module!(
+    seconds_required,
+    super::SecondsRequired,
+    "(De)serializes a `Cron` requiring a seconds field, for use with \
+     `#[serde(with = \"croner::config::seconds_required\")]`."
+);
+
+#[cfg(feature = "serde")]
+serde_with_module!(
+    alternative_weekdays,
+    super::AlternativeWeekdays,
+    "(De)serializes a `Cron` using Quartz-style weekdays, for use with \
+     `#[serde(with = \"croner::config::alternative_weekdays\")]`."
+);
+
+#[cfg(feature = "serde")]
+serde_with_module!(
+    dom_and_dow,
+    super::DomAndDow,
+    "(De)serializes a `Cron` combining day of month and day of week, for use with \
+     `#[serde(with = \"croner::config::dom_and_dow\")]`."
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::errors::CronError;
+
+    #[test]
+    fn test_cron_with_config() -> Result<(), CronError> {
+        let cron = CronWith::<SecondsRequired>::parse("*/10 * * * * *")?;
+        assert!(cron.pattern.with_seconds_required);
+        assert!(CronWith::<SecondsRequired>::parse("* * * * *").is_err());
+        assert!(CronWith::<DefaultConfig>::parse("*/10 * * * * *").is_err());
+
+        let cron = CronWith::<(SecondsOptional, DomAndDow)>::parse("0 0 0 31 12 FRI")?;
+        assert!(cron.pattern.dom_and_dow);
+        Ok(())
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_deserialize_with_config() {
+        use serde::de::{value, IntoDeserializer};
+        use serde_test::{assert_de_tokens_error, assert_ser_tokens, Token};
+
+        let deserializer: value::StrDeserializer<value::Error> =
+            "0 0 12 * * MON".into_deserializer();
+        let cron = CronWith::<SecondsRequired>::deserialize(deserializer).unwrap();
+        assert_eq!(cron.as_str(), "0 0 12 * * 1");
+        assert_ser_tokens(&cron, &[Token::Str("0 0 12 * * 1")]);
+
+        let deserializer: value::StrDeserializer<value::Error> = "0 12 * * MON".into_deserializer();
+        assert!(deserialize_with_config::<_, SecondsRequired>(deserializer).is_err());
+
+        assert_de_tokens_error::<CronWith<SecondsRequired>>(
+            &[Token::Str("0 12 * * MON")],
+            "Invalid pattern: Pattern must consist of six fields, seconds can not be omitted.",
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_with_modules() {
+        use serde::de::{value, IntoDeserializer};
+        use serde_test::{assert_ser_tokens, Token};
+
+        // A 6-field pattern deserializes when seconds are optional, but not by default
+        let deserializer: value::StrDeserializer<value::Error> =
+            "*/10 * * * * *".into_deserializer();
+        let cron = seconds_optional::deserialize(deserializer).unwrap();
+        assert!(cron.pattern.with_seconds_optional);
+        assert_eq!(cron.as_str(), "*/10 * * * * *");
+
+        let deserializer: value::StrDeserializer<value::Error> =
+            "*/10 * * * * *".into_deserializer();
+        assert!(Cron::deserialize(deserializer).is_err());
+
+        // Serializing writes the plain pattern string
+        struct WithModule(Cron);
+        impl Serialize for WithModule {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                seconds_optional::serialize(&self.0, serializer)
+            }
+        }
+        assert_ser_tokens(&WithModule(cron), &[Token::Str("*/10 * * * * *")]);
+
+        let deserializer: value::StrDeserializer<value::Error> = "0 0 * * 2".into_deserializer();
+        let cron = alternative_weekdays::deserialize(deserializer).unwrap();
+        assert!(cron.pattern.with_alternative_weekdays);
+
+        let deserializer: value::StrDeserializer<value::Error> =
+            "0 0 0 13 * FRI".into_deserializer();
+        assert!(dom_and_dow::deserialize(deserializer).is_err());
+        let deserializer: value::StrDeserializer<value::Error> = "0 0 13 * FRI".into_deserializer();
+        assert!(
+            dom_and_dow::deserialize(deserializer)
+                .unwrap()
+                .pattern
+                .dom_and_dow
+        );
+
+        let deserializer: value::StrDeserializer<value::Error> = "0 0 * * *".into_deserializer();
+        assert!(seconds_required::deserialize(deserializer).is_err());
+    }
+}
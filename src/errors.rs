@@ -1,3 +1,5 @@
+use alloc::string::String;
+
 /// Represents errors that can occur while parsing and evaluating cron patterns.
 ///
 /// `CronError` is used throughout the `croner` crate to indicate various types of failures
@@ -41,9 +43,48 @@ pub enum CronError {
     /// This variant is used for various errors that specifically arise from individual components of a cron pattern,
     /// such as "Position x is out of bounds for the current range (y-z).".
     ComponentError(String),
+
+    /// A field of the pattern is invalid.
+    ///
+    /// This variant is returned when parsing a pattern fails in one of its fields, and carries enough context
+    /// to point out the offending token, such as "minute field: '60' at offset 2: Number out of range 0-59.".
+    FieldError {
+        /// The field that failed to parse.
+        field: CronField,
+        /// The offending part of the field as written, usually a single entry of a list.
+        part: String,
+        /// Byte offset of `part` into the pattern as given to `Cron::new`.
+        offset: usize,
+        /// Description of what is wrong with `part`.
+        reason: String,
+    },
+}
+
+/// Identifies a field of a cron pattern, used to give context to a [`CronError::FieldError`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CronField {
+    Second,
+    Minute,
+    Hour,
+    DayOfMonth,
+    Month,
+    DayOfWeek,
+}
+impl core::fmt::Display for CronField {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        let name = match self {
+            CronField::Second => "second",
+            CronField::Minute => "minute",
+            CronField::Hour => "hour",
+            CronField::DayOfMonth => "day-of-month",
+            CronField::Month => "month",
+            CronField::DayOfWeek => "day-of-week",
+        };
+        write!(f, "{}", name)
+    }
 }
-impl std::fmt::Display for CronError {
-    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+impl core::fmt::Display for CronError {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
         match self {
             CronError::TimeSearchLimitExceeded => {
                 write!(f, "CronScheduler time search limit exceeded.")
@@ -56,7 +97,18 @@ impl std::fmt::Display for CronError {
                 write!(f, "Pattern contains illegal characters: {}", msg)
             }
             CronError::ComponentError(msg) => write!(f, "Component error: {}", msg),
+            CronError::FieldError {
+                field,
+                part,
+                offset,
+                reason,
+            } => write!(
+                f,
+                "{} field: '{}' at offset {}: {}",
+                field, part, offset, reason
+            ),
         }
     }
 }
+#[cfg(feature = "std")]
 impl std::error::Error for CronError {}
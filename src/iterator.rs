@@ -1,12 +1,12 @@
+use crate::errors::CronError;
 use crate::Cron;
-use chrono::{DateTime, Duration, TimeZone};
+use chrono::{DateTime, TimeZone};
 
 pub struct CronIterator<Tz>
 where
     Tz: TimeZone,
 {
-    cron: Cron,
-    current_time: DateTime<Tz>,
+    inner: CronTryIterator<Tz>,
 }
 
 impl<Tz> CronIterator<Tz>
@@ -15,12 +15,96 @@ where
 {
     pub fn new(cron: Cron, start_time: DateTime<Tz>) -> Self {
         CronIterator {
-            cron,
-            current_time: start_time,
+            inner: CronTryIterator::new(cron, start_time, true),
+        }
+    }
+
+    // Stop yielding once the found time passes end_time, end_time itself is included.
+    // The bounded iterator can also be traversed from the end using next_back
+    pub fn with_end(self, end_time: DateTime<Tz>) -> CronBoundedIterator<Tz> {
+        CronBoundedIterator {
+            inner: self.inner.with_end(end_time),
+        }
+    }
+
+    // Ends the iteration once max_searches passes over the time components have been used up
+    pub(crate) fn with_max_searches(mut self, max_searches: u64) -> Self {
+        self.inner.searches_left = Some(max_searches);
+        self
+    }
+
+    /// Stops yielding at `end_time`, which is only yielded itself if `inclusive` is `true`.
+    ///
+    /// Reads naturally after `iter_after` or `iter_from`, and like `with_end` returns a
+    /// [`CronBoundedIterator`], which can also be traversed from the end.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::{TimeZone, Utc};
+    /// use croner::Cron;
+    ///
+    /// let cron = Cron::new("* * * * *").parse().expect("Couldn't parse cron string");
+    /// let start = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+    /// let end = Utc.with_ymd_and_hms(2024, 1, 2, 0, 0, 0).unwrap();
+    ///
+    /// // Every minute of the day, without midnight of the next day
+    /// let minutes: Vec<_> = cron.iter_from(start).until(end, false).collect();
+    /// assert_eq!(minutes.len(), 24 * 60);
+    /// ```
+    pub fn until(self, end_time: DateTime<Tz>, inclusive: bool) -> CronBoundedIterator<Tz> {
+        CronBoundedIterator {
+            inner: self.inner.until(end_time, inclusive),
+        }
+    }
+
+    /// Yields the first occurrence and then every `step`-th occurrence after it, like
+    /// `Iterator::step_by`. Skipped occurrences are searched past without being handed out.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `step` is 0.
+    pub fn step_by_occurrences(self, step: usize) -> CronStepBy<Tz> {
+        assert!(
+            step != 0,
+            "step_by_occurrences requires a step of at least 1"
+        );
+        CronStepBy {
+            inner: self.inner,
+            step,
+            first_taken: false,
         }
     }
 }
 
+/// An iterator yielding every n-th occurrence, created by `CronIterator::step_by_occurrences`.
+pub struct CronStepBy<Tz>
+where
+    Tz: TimeZone,
+{
+    inner: CronTryIterator<Tz>,
+    step: usize,
+    first_taken: bool,
+}
+
+impl<Tz> Iterator for CronStepBy<Tz>
+where
+    Tz: TimeZone,
+{
+    type Item = DateTime<Tz>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // The first occurrence is yielded as is, after that step - 1 occurrences are skipped
+        if self.first_taken {
+            for _ in 1..self.step {
+                self.inner.next()?.ok()?;
+            }
+        }
+        self.first_taken = true;
+        self.inner.next()?.ok()
+    }
+}
+
 impl<Tz> Iterator for CronIterator<Tz>
 where
     Tz: TimeZone,
@@ -28,19 +112,236 @@ where
     type Item = DateTime<Tz>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        match self.cron.find_next_occurrence(&self.current_time, true) {
+        // Stop the iteration if we cannot find the next occurrence
+        self.inner.next()?.ok()
+    }
+}
+
+/// An iterator over the occurrences of a cron pattern up to an end time, created by
+/// `Cron::iter_between` or `CronIterator::until`.
+///
+/// Unlike an unbounded [`CronIterator`], it can also be traversed from the end, so
+/// `.rev()` and `.next_back()` walk backwards from the end time.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{TimeZone, Utc};
+/// use croner::Cron;
+///
+/// let cron = Cron::new("0 * * * *").parse().expect("Couldn't parse cron string");
+/// let start = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+/// let end = Utc.with_ymd_and_hms(2024, 1, 1, 12, 0, 0).unwrap();
+///
+/// // The last three runs before the deadline, latest first
+/// let last_runs: Vec<_> = cron.iter_between(start, end).rev().take(3).collect();
+/// assert_eq!(last_runs[0], end);
+/// ```
+///
+/// An iterator without an end has nowhere to start from backwards, so it can not be reversed:
+///
+/// ```compile_fail
+/// use chrono::Utc;
+/// use croner::Cron;
+///
+/// let cron = Cron::new("0 * * * *").parse().expect("Couldn't parse cron string");
+/// let _ = cron.iter_after(Utc::now()).rev();
+/// ```
+pub struct CronBoundedIterator<Tz>
+where
+    Tz: TimeZone,
+{
+    inner: CronTryBoundedIterator<Tz>,
+}
+
+impl<Tz> CronBoundedIterator<Tz>
+where
+    Tz: TimeZone,
+{
+    /// Yields the first occurrence and then every `step`-th occurrence after it, stopping at the
+    /// end time. See `CronIterator::step_by_occurrences`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `step` is 0.
+    pub fn step_by_occurrences(self, step: usize) -> CronStepBy<Tz> {
+        CronIterator {
+            inner: self.inner.inner,
+        }
+        .step_by_occurrences(step)
+    }
+}
+
+impl<Tz> Iterator for CronBoundedIterator<Tz>
+where
+    Tz: TimeZone,
+{
+    type Item = DateTime<Tz>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // Stop the iteration if we cannot find the next occurrence
+        self.inner.next()?.ok()
+    }
+}
+
+impl<Tz> DoubleEndedIterator for CronBoundedIterator<Tz>
+where
+    Tz: TimeZone,
+{
+    // Yields occurrences backwards from the end bound
+    fn next_back(&mut self) -> Option<Self::Item> {
+        // Stop the iteration if we cannot find the previous occurrence
+        self.inner.next_back()?.ok()
+    }
+}
+
+/// An iterator over the occurrences of a cron pattern that surfaces errors.
+///
+/// Yields `Ok` for each occurrence. Running out of occurrences ends the iteration, while
+/// any other error is yielded once as `Err`, after which the iteration ends.
+pub struct CronTryIterator<Tz>
+where
+    Tz: TimeZone,
+{
+    cron: Cron,
+    current_time: DateTime<Tz>,
+    inclusive: bool,
+    end_time: Option<DateTime<Tz>>,
+    end_inclusive: bool,
+    searches_left: Option<u64>,
+    finished: bool,
+}
+
+impl<Tz> CronTryIterator<Tz>
+where
+    Tz: TimeZone,
+{
+    pub fn new(cron: Cron, start_time: DateTime<Tz>, inclusive: bool) -> Self {
+        CronTryIterator {
+            cron,
+            current_time: start_time,
+            inclusive,
+            end_time: None,
+            end_inclusive: true,
+            searches_left: None,
+            finished: false,
+        }
+    }
+
+    // Stop yielding once the found time passes end_time, end_time itself is included
+    pub fn with_end(self, end_time: DateTime<Tz>) -> CronTryBoundedIterator<Tz> {
+        self.until(end_time, true)
+    }
+
+    /// Stops yielding at `end_time`, which is only yielded itself if `inclusive` is `true`.
+    ///
+    /// Returns a [`CronTryBoundedIterator`], which can also be traversed from the end.
+    pub fn until(mut self, end_time: DateTime<Tz>, inclusive: bool) -> CronTryBoundedIterator<Tz> {
+        self.end_time = Some(end_time);
+        self.end_inclusive = inclusive;
+        CronTryBoundedIterator { inner: self }
+    }
+
+    // Ends the iteration, turning running out of occurrences into a regular end
+    fn finish(&mut self, error: CronError) -> Option<Result<DateTime<Tz>, CronError>> {
+        self.finished = true;
+        match error {
+            CronError::TimeSearchLimitExceeded => None,
+            error => Some(Err(error)),
+        }
+    }
+}
+
+impl<Tz> Iterator for CronTryIterator<Tz>
+where
+    Tz: TimeZone,
+{
+    type Item = Result<DateTime<Tz>, CronError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.finished {
+            return None;
+        }
+        let next = match &mut self.searches_left {
+            Some(searches_left) => self.cron.find_next_occurrence_limited(
+                &self.current_time,
+                self.inclusive,
+                None,
+                searches_left,
+            ),
+            None => self
+                .cron
+                .find_next_occurrence(&self.current_time, self.inclusive),
+        };
+        match next {
             Ok(next_time) => {
-                // Check if we can add one second without overflow
-                let next_time_clone = next_time.clone();
-                if let Some(updated_time) = next_time.checked_add_signed(Duration::seconds(1)) {
-                    self.current_time = updated_time;
-                    Some(next_time_clone) // Return the next time
-                } else {
-                    // If we hit an overflow, stop the iteration
-                    None
+                // Stop the iteration if the found time is beyond the bound
+                if let Some(end_time) = &self.end_time {
+                    if next_time > *end_time || (next_time == *end_time && !self.end_inclusive) {
+                        self.finished = true;
+                        return None;
+                    }
+                }
+                self.current_time = next_time.clone();
+                self.inclusive = false;
+                Some(Ok(next_time))
+            }
+            Err(error) => self.finish(error),
+        }
+    }
+}
+
+/// An iterator over the occurrences of a cron pattern up to an end time that surfaces errors,
+/// created by `CronTryIterator::until`.
+///
+/// The fallible counterpart of [`CronBoundedIterator`], which can also be traversed from the end.
+pub struct CronTryBoundedIterator<Tz>
+where
+    Tz: TimeZone,
+{
+    // The end time is kept in the inner iterator, which stops there going forward
+    inner: CronTryIterator<Tz>,
+}
+
+impl<Tz> Iterator for CronTryBoundedIterator<Tz>
+where
+    Tz: TimeZone,
+{
+    type Item = Result<DateTime<Tz>, CronError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next()
+    }
+}
+
+impl<Tz> DoubleEndedIterator for CronTryBoundedIterator<Tz>
+where
+    Tz: TimeZone,
+{
+    // Yields occurrences backwards from the end bound
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let inner = &mut self.inner;
+        if inner.finished {
+            return None;
+        }
+        let end_time = inner.end_time.as_ref()?;
+        match inner
+            .cron
+            .find_previous_occurrence(end_time, inner.end_inclusive)
+        {
+            Ok(previous_time) => {
+                // Stop the iteration if the found time is before the remaining range
+                if previous_time < inner.current_time
+                    || (previous_time == inner.current_time && !inner.inclusive)
+                {
+                    inner.finished = true;
+                    return None;
                 }
+                inner.end_time = Some(previous_time.clone());
+                inner.end_inclusive = false;
+                Some(Ok(previous_time))
             }
-            Err(_) => None, // Stop the iteration if we cannot find the next occurrence
+            Err(error) => inner.finish(error),
         }
     }
 }
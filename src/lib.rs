@@ -8,11 +8,17 @@
 //! - Supports time zone-aware scheduling.
 //! - Offers granularity up to seconds for precise task scheduling.
 //! - Compatible with the `chrono` library for dealing with date and time in Rust.
-//! 
+//!
 //! ## Crate Features
 //! - `serde`: Enables [`serde::Serialize`](https://docs.rs/serde/1/serde/trait.Serialize.html) and
 //!   [`serde::Deserialize`](https://docs.rs/serde/1/serde/trait.Deserialize.html) implementations for
-//!   [`Cron`](struct.Cron.html). This feature is disabled by default.
+//!   [`Cron`](struct.Cron.html). This feature is disabled by default. Patterns that need a non-default
+//!   parser configuration can be deserialized using [`config::CronWith`].
+//!   The parsed fields can be (de)serialized as lists of values using [`structured::CronStructured`].
+//! - `std`: Enabled by default. Without it the crate is `no_std` and only needs `alloc`, for
+//!   embedded and WASM targets. Parsing, matching and searching for occurrences all remain
+//!   available, as they are pure computation over `chrono` types. `CronError` only implements
+//!   `std::error::Error` with this feature.
 //!
 //! ## Example
 //! The following example demonstrates how to use Croner to parse a cron expression and find the next occurrence of a specified time:
@@ -29,7 +35,7 @@
 //!
 //! println!(
 //!     "Pattern \"{}\" will match next at {}",
-//!     cron.pattern.to_string(),
+//!     cron.pattern().to_string(),
 //!     next
 //! );
 //! ```
@@ -60,28 +66,43 @@
 //!
 //! | Field        | Required | Allowed values  | Allowed special characters | Remarks                                                                                                         |
 //! | ------------ | -------- | --------------- | -------------------------- | --------------------------------------------------------------------------------------------------------------- |
-//! | Seconds      | Optional | 0-59            | * , - / ?                  |                                                                                                                 |
-//! | Minutes      | Yes      | 0-59            | * , - / ?                  |                                                                                                                 |
-//! | Hours        | Yes      | 0-23            | * , - / ?                  |                                                                                                                 |
-//! | Day of Month | Yes      | 1-31            | * , - / ? L W              |                                                                                                                 |
+//! | Seconds      | Optional | 0-59            | * , - / ? H                |                                                                                                                 |
+//! | Minutes      | Yes      | 0-59            | * , - / ? H                |                                                                                                                 |
+//! | Hours        | Yes      | 0-23            | * , - / ? H                |                                                                                                                 |
+//! | Day of Month | Yes      | 1-31            | * , - / ? L W H            | `L-n` is the nth-to-last day of the month                                                                       |
 //! | Month        | Yes      | 1-12 or JAN-DEC | * , - / ?                  |                                                                                                                 |
-//! | Day of Week  | Yes      | 0-7 or SUN-MON  | * , - / ? # L              | 0 to 6 are Sunday to Saturday, 7 is Sunday, the same as 0. '#' is used to specify the nth occurrence of a weekday |
+//! | Day of Week  | Yes      | 0-7 or SUN-MON  | * , - / ? # L +            | 0 to 6 are Sunday to Saturday, 7 is Sunday, the same as 0. '#' is used to specify the nth occurrence of a weekday. A leading '+' requires both day fields to match |
 //!
 //! For more information, refer to the full [README](https://github.com/hexagon/croner-rust).
 
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+extern crate alloc;
+
+pub mod config;
 pub mod errors;
 
 mod component;
 mod iterator;
 mod pattern;
-
+mod quartz;
+pub mod structured;
+mod systemd;
+
+use alloc::{string::String, vec, vec::Vec};
+use component::{Direction, ALL_BIT, LAST_BIT, NTH_ALL};
+use core::hash::{Hash, Hasher};
+use core::ops::RangeInclusive;
+use core::str::FromStr;
 use errors::CronError;
-pub use iterator::CronIterator;
-use pattern::CronPattern;
-use std::str::FromStr;
+pub use iterator::{
+    CronBoundedIterator, CronIterator, CronStepBy, CronTryBoundedIterator, CronTryIterator,
+};
+pub use pattern::{CronPattern, DstGapPolicy, DstOverlapPolicy, Locale, MatchReport};
 
 use chrono::{
-    DateTime, Datelike, Duration, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Timelike,
+    DateTime, Datelike, Duration, LocalResult, NaiveDate, NaiveDateTime, NaiveTime, Offset,
+    TimeZone, Timelike,
 };
 
 #[cfg(feature = "serde")]
@@ -93,6 +114,7 @@ use serde::{
 };
 
 const YEAR_UPPER_LIMIT: i32 = 5000;
+const YEAR_LOWER_LIMIT: i32 = 1;
 
 enum TimeComponent {
     Second = 1,
@@ -103,32 +125,100 @@ enum TimeComponent {
     Year,
 }
 
+/// A calendar unit of local time, used to select the minute, hour or day containing a time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TimeUnit {
+    Minute,
+    Hour,
+    Day,
+}
+
+/// The direction in which to search for occurrences.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SearchDirection {
+    /// Towards later times, like `find_next_occurrence`.
+    Forward,
+    /// Towards earlier times, like `find_previous_occurrence`.
+    Backward,
+}
+
 // The Cron struct represents a cron schedule and provides methods to parse cron strings,
 // check if a datetime matches the cron pattern, and find the next occurrence.
 #[derive(Debug, Clone)]
 pub struct Cron {
-    pub pattern: CronPattern, // Parsed cron pattern
+    pattern: CronPattern, // Parsed cron pattern, read it through pattern()
 }
 impl Cron {
-    // Constructor to create a new instance of Cron with default settings
+    /// Creates an unparsed `Cron` with the default configuration, the entry point for
+    /// configuring and parsing a pattern.
+    ///
+    /// Chain any of the `with_*` methods to change how the pattern is read, and finish with
+    /// `parse`. Nothing is validated until `parse` is called.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use croner::Cron;
+    ///
+    /// let cron = Cron::new("0 0 12 * * 2-6")
+    ///     .with_seconds_required()
+    ///     .with_alternative_weekdays()
+    ///     .parse()
+    ///     .expect("Couldn't parse cron string");
+    /// assert_eq!(cron.canonical(), "0 0 12 * * 1-5");
+    /// ```
     pub fn new(cron_string: &str) -> Self {
         Self {
             pattern: CronPattern::new(cron_string),
         }
     }
 
-    // Tries to parse a given cron string into a Cron instance.
+    /// Parses the pattern using the configuration set so far, returning the parsed `Cron`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `CronError` describing the problem when the pattern is invalid for the
+    /// configuration. Invalid input never panics, for example weekday 0 is rejected when
+    /// weekdays are numbered 1-7 by `with_alternative_weekdays`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use croner::Cron;
+    ///
+    /// assert!(Cron::new("0 0 * * 0").parse().is_ok());
+    /// assert!(Cron::new("0 0 * * 0").with_alternative_weekdays().parse().is_err());
+    /// ```
     pub fn parse(&mut self) -> Result<Cron, CronError> {
         self.pattern.parse()?;
         Ok(self.clone())
     }
 
+    /// Parses a six-field pattern with a leading seconds field, a shorthand for
+    /// `Cron::new(pattern).with_seconds_required().parse()`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use croner::Cron;
+    ///
+    /// let cron = Cron::parse_with_seconds("30 0 12 * * *").expect("Couldn't parse cron string");
+    /// assert_eq!(cron.canonical(), "30 0 12 * * *");
+    ///
+    /// // Five-field patterns are rejected
+    /// assert!(Cron::parse_with_seconds("0 12 * * *").is_err());
+    /// ```
+    pub fn parse_with_seconds(pattern: &str) -> Result<Cron, CronError> {
+        Cron::new(pattern).with_seconds_required().parse()
+    }
+
     /// Evaluates if a given `DateTime` matches the cron pattern associated with this instance.
     ///
     /// The function checks each cron field (seconds, minutes, hours, day of month, month) against
     /// the provided `DateTime` to determine if it aligns with the cron pattern. Each field is
     /// checked for a match, and all fields must match for the entire pattern to be considered
-    /// a match.
+    /// a match. A leap second, which `chrono` represents as second 59 with an extra second of
+    /// nanoseconds, matches the same as second 59.
     ///
     /// # Parameters
     ///
@@ -162,18 +252,106 @@ impl Cron {
     /// println!("Time is: {}", time);
     /// println!(
     ///     "Pattern \"{}\" does {} time {}",
-    ///     cron.pattern.to_string(),
+    ///     cron.pattern().to_string(),
     ///     if matches_all { "match" } else { "not match" },
     ///     time
     /// );
     /// ```
     pub fn is_time_matching<Tz: TimeZone>(&self, time: &DateTime<Tz>) -> Result<bool, CronError> {
         // Convert to NaiveDateTime
+        self.is_naive_time_matching(&time.naive_local())
+    }
+
+    /// Evaluates if a given `DateTime` matches the pattern on the wall clock of another timezone.
+    ///
+    /// `time` is converted into `timezone` before its fields are checked, so a UTC timestamp can
+    /// be matched against a pattern meant for, say, `America/New_York`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::{FixedOffset, TimeZone, Utc};
+    /// use croner::Cron;
+    ///
+    /// let cron = Cron::new("0 12 * * *").parse().expect("Couldn't parse cron string");
+    /// let time = Utc.with_ymd_and_hms(2024, 1, 1, 17, 0, 0).unwrap();
+    /// let new_york = FixedOffset::west_opt(5 * 3600).unwrap();
+    ///
+    /// assert!(!cron.is_time_matching(&time).unwrap());
+    /// assert!(cron.is_time_matching_in(&time, &new_york).unwrap());
+    /// ```
+    pub fn is_time_matching_in<Tz: TimeZone, Tz2: TimeZone>(
+        &self,
+        time: &DateTime<Tz>,
+        timezone: &Tz2,
+    ) -> Result<bool, CronError> {
+        self.is_time_matching(&time.with_timezone(timezone))
+    }
+
+    /// Evaluates if a given `DateTime` falls within a minute matched by the pattern, ignoring
+    /// the seconds of both the time and the pattern.
+    ///
+    /// Useful for pollers ticking once a minute that can not guarantee to land on the exact
+    /// second, a tick at 09:30:03 still matches `0 30 9 * * *`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::{TimeZone, Utc};
+    /// use croner::Cron;
+    ///
+    /// let cron = Cron::new("0 30 9 * * *")
+    ///     .with_seconds_required()
+    ///     .parse()
+    ///     .expect("Couldn't parse cron string");
+    /// let tick = Utc.with_ymd_and_hms(2024, 1, 1, 9, 30, 3).unwrap();
+    /// assert!(!cron.is_time_matching(&tick).unwrap());
+    /// assert!(cron.is_time_matching_ignoring_seconds(&tick).unwrap());
+    /// ```
+    pub fn is_time_matching_ignoring_seconds<Tz: TimeZone>(
+        &self,
+        time: &DateTime<Tz>,
+    ) -> Result<bool, CronError> {
+        self.is_naive_minute_matching(&time.naive_local())
+    }
+
+    /// Reports which day-of-month and day-of-week rules made `time` match the pattern.
+    ///
+    /// Returns `None` if `time` does not match the pattern.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::{TimeZone, Utc};
+    /// use croner::Cron;
+    ///
+    /// let cron = Cron::new("0 0 * * 2#2").parse().expect("Couldn't parse cron string");
+    /// let time = Utc.with_ymd_and_hms(2024, 1, 9, 0, 0, 0).unwrap();
+    ///
+    /// let report = cron.match_report(&time).unwrap().expect("time should match");
+    /// assert!(report.nth_weekday);
+    /// ```
+    pub fn match_report<Tz: TimeZone>(
+        &self,
+        time: &DateTime<Tz>,
+    ) -> Result<Option<MatchReport>, CronError> {
         let naive_time = time.naive_local();
+        if !self.is_naive_time_matching(&naive_time)? {
+            return Ok(None);
+        }
+        self.pattern
+            .day_match_report(naive_time.year(), naive_time.month(), naive_time.day())
+    }
 
-        // Use NaiveDateTime for the comparisons
+    // Use NaiveDateTime for the comparisons
+    fn is_naive_time_matching(&self, naive_time: &NaiveDateTime) -> Result<bool, CronError> {
         Ok(self.pattern.second_match(naive_time.second())?
-            && self.pattern.minute_match(naive_time.minute())?
+            && self.is_naive_minute_matching(naive_time)?)
+    }
+
+    // Checks every field except the seconds
+    fn is_naive_minute_matching(&self, naive_time: &NaiveDateTime) -> Result<bool, CronError> {
+        Ok(self.pattern.minute_match(naive_time.minute())?
             && self.pattern.hour_match(naive_time.hour())?
             && self
                 .pattern
@@ -207,7 +385,8 @@ impl Cron {
     /// - `CronError::InvalidTime`: If the start time provided is invalid or adjustments to the
     ///   time result in an invalid date/time.
     /// - `CronError::TimeSearchLimitExceeded`: If the search exceeds a reasonable time limit.
-    ///   This prevents infinite loops in case of patterns that cannot be matched.
+    ///   This prevents infinite loops in case of patterns that cannot be matched, and is also
+    ///   returned when the search runs past the year 5000.
     /// - Other errors as defined by the `CronError` enum may occur if the pattern match fails
     ///   at any stage of the search.
     ///
@@ -226,14 +405,31 @@ impl Cron {
     ///
     /// println!(
     ///     "Pattern \"{}\" will match next time at {}",
-    ///     cron.pattern.to_string(),
+    ///     cron.pattern().to_string(),
     ///     next
     /// );
     /// ```
-    pub fn find_next_occurrence<Tz: TimeZone>(
+    pub fn find_next_occurrence<Tz>(
+        &self,
+        start_time: &DateTime<Tz>,
+        inclusive: bool,
+    ) -> Result<DateTime<Tz>, CronError>
+    where
+        Tz: TimeZone,
+    {
+        let mut searches_left = u64::MAX;
+        self.find_next_occurrence_limited(start_time, inclusive, None, &mut searches_left)
+    }
+
+    // Searches like find_next_occurrence, using up one of searches_left for every pass over the
+    // time components. Running out of searches, or passing the wall-clock time until, ends the
+    // search with TimeSearchLimitExceeded
+    pub(crate) fn find_next_occurrence_limited<Tz>(
         &self,
         start_time: &DateTime<Tz>,
         inclusive: bool,
+        until: Option<&NaiveDateTime>,
+        searches_left: &mut u64,
     ) -> Result<DateTime<Tz>, CronError>
     where
         Tz: TimeZone,
@@ -241,566 +437,3536 @@ impl Cron {
         let mut naive_time = start_time.naive_local();
         let originaltimezone = start_time.timezone();
 
-        if !inclusive {
+        let is_after_start = |tz_datetime: &DateTime<Tz>| {
+            *tz_datetime > *start_time || (inclusive && *tz_datetime == *start_time)
+        };
+
+        // If start_time is the earlier of two instants sharing a wall-clock time, the second pass
+        // of the repeated wall-clock times before it still lies ahead, and comes before every
+        // time following the repetition. The second pass is then only looked for here
+        let mut in_first_pass = false;
+        let mut second_pass = None;
+        if let LocalResult::Ambiguous(earliest, latest) =
+            originaltimezone.from_local_datetime(&naive_time)
+        {
+            if earliest == *start_time {
+                in_first_pass = true;
+                let overlap = latest.signed_duration_since(earliest);
+                let mut repeated_time = first_repeated_time(naive_time, overlap, &originaltimezone);
+                self.find_next_naive_match(&mut repeated_time, until, searches_left)?;
+                second_pass = self
+                    .resolve_local_time(repeated_time, &originaltimezone)?
+                    .into_iter()
+                    .find(is_after_start);
+            }
+        }
+
+        // Convert each match back to the original timezone, and return the first instant after
+        // start_time
+        let next = loop {
+            if let Err(error) = self.find_next_naive_match(&mut naive_time, until, searches_left) {
+                break Err(error);
+            }
+            let later_pass = match originaltimezone.from_local_datetime(&naive_time) {
+                LocalResult::Ambiguous(_, latest) if in_first_pass => Some(latest),
+                _ => None,
+            };
+            if let Some(tz_datetime) = self
+                .resolve_local_time(naive_time, &originaltimezone)?
+                .into_iter()
+                .filter(|tz_datetime| Some(tz_datetime) != later_pass.as_ref())
+                .find(is_after_start)
+            {
+                break Ok(tz_datetime);
+            }
             naive_time = naive_time
-                .checked_add_signed(chrono::Duration::seconds(1))
-                .ok_or(CronError::InvalidTime)?;
+                .checked_add_signed(Duration::seconds(1))
+                .ok_or(CronError::TimeSearchLimitExceeded)?;
+        };
+
+        match (next, second_pass) {
+            (Ok(next), Some(second_pass)) => Ok(next.min(second_pass)),
+            (Err(CronError::TimeSearchLimitExceeded), Some(second_pass)) => Ok(second_pass),
+            (next, _) => next,
+        }
+    }
+
+    // Moves naive_time forward to the first wall-clock time matching the pattern, at or after it,
+    // giving up once it passes until
+    fn find_next_naive_match(
+        &self,
+        naive_time: &mut NaiveDateTime,
+        until: Option<&NaiveDateTime>,
+        searches_left: &mut u64,
+    ) -> Result<(), CronError> {
+        // Times past the last searched year are never returned
+        if naive_time.year() > YEAR_UPPER_LIMIT {
+            return Err(CronError::TimeSearchLimitExceeded);
         }
 
         loop {
+            *searches_left = searches_left
+                .checked_sub(1)
+                .ok_or(CronError::TimeSearchLimitExceeded)?;
             let mut updated = false;
 
-            updated |= self.find_next_matching_month(&mut naive_time)?;
-            updated |= self.find_next_matching_day(&mut naive_time)?;
-            updated |= self.find_next_matching_hour(&mut naive_time)?;
-            updated |= self.find_next_matching_minute(&mut naive_time)?;
-            updated |= self.find_next_matching_second(&mut naive_time)?;
+            updated |= self.find_next_matching_month(naive_time)?;
+            updated |= self.find_next_matching_day(naive_time)?;
+            updated |= self.find_next_matching_hour(naive_time)?;
+            updated |= self.find_next_matching_minute(naive_time)?;
+            updated |= self.find_next_matching_second(naive_time)?;
 
-            if updated {
-                continue;
+            if matches!(until, Some(until) if *naive_time > *until) {
+                return Err(CronError::TimeSearchLimitExceeded);
             }
+            if !updated {
+                break;
+            }
+        }
 
-            // Convert back to original timezone
-            let tz_datetime_result = from_naive(naive_time, &originaltimezone)?;
+        // Check for match
+        if !self.is_naive_time_matching(naive_time)? {
+            return Err(CronError::TimeSearchLimitExceeded);
+        }
+        Ok(())
+    }
 
-            // Check for match
-            if self.is_time_matching(&tz_datetime_result)? {
-                return Ok(tz_datetime_result);
-            } else {
-                return Err(CronError::TimeSearchLimitExceeded);
+    // Moves naive_time backward to the last wall-clock time matching the pattern, at or before it
+    fn find_previous_naive_match(&self, naive_time: &mut NaiveDateTime) -> Result<(), CronError> {
+        // Times before the first searched year are never returned
+        if naive_time.year() < YEAR_LOWER_LIMIT {
+            return Err(CronError::TimeSearchLimitExceeded);
+        }
+
+        loop {
+            let mut updated = false;
+
+            updated |= self.find_previous_matching_month(naive_time)?;
+            updated |= self.find_previous_matching_day(naive_time)?;
+            updated |= self.find_previous_matching_hour(naive_time)?;
+            updated |= self.find_previous_matching_minute(naive_time)?;
+            updated |= self.find_previous_matching_second(naive_time)?;
+
+            if !updated {
+                break;
             }
         }
+
+        // Check for match
+        if !self.is_naive_time_matching(naive_time)? {
+            return Err(CronError::TimeSearchLimitExceeded);
+        }
+        Ok(())
     }
 
-    /// Creates a `CronIterator` starting from the specified time.
+    /// Finds the previous occurrence of a scheduled time that matches the cron pattern,
+    /// searching backwards from a given `start_time`.
     ///
-    /// This function will create an iterator that yields dates and times that
-    /// match a cron schedule, beginning at `start_from`. The iterator will
-    /// begin at the specified start time if it matches.
+    /// If `inclusive` is `true`, `start_time` itself is returned if it matches the pattern.
     ///
     /// # Examples
     ///
     /// ```
-    /// use chrono::Utc;
+    /// use chrono::{TimeZone, Utc};
     /// use croner::Cron;
     ///
-    /// // Parse cron expression
-    /// let cron = Cron::new("* * * * *").parse().expect("Couldn't parse cron string");
+    /// let cron = Cron::new("0 12 * * *").parse().expect("Couldn't parse cron string");
+    /// let time = Utc.with_ymd_and_hms(2024, 1, 2, 9, 0, 0).unwrap();
     ///
-    /// // Compare to time now
-    /// let time = Utc::now();
+    /// let previous = cron.find_previous_occurrence(&time, false).unwrap();
+    /// assert_eq!(previous, Utc.with_ymd_and_hms(2024, 1, 1, 12, 0, 0).unwrap());
+    /// ```
     ///
-    /// // Get next 5 matches using iter_from
-    /// println!("Finding matches of pattern '{}' starting from {}:", cron.pattern.to_string(), time);
+    /// # Errors
+    ///
+    /// Returns `CronError::TimeSearchLimitExceeded` if no occurrence is found before year 1.
+    pub fn find_previous_occurrence<Tz>(
+        &self,
+        start_time: &DateTime<Tz>,
+        inclusive: bool,
+    ) -> Result<DateTime<Tz>, CronError>
+    where
+        Tz: TimeZone,
+    {
+        let mut naive_time = start_time
+            .naive_local()
+            .with_nanosecond(0)
+            .ok_or(CronError::InvalidTime)?;
+        let originaltimezone = start_time.timezone();
+
+        let is_before_start = |tz_datetime: &DateTime<Tz>| {
+            *tz_datetime < *start_time || (inclusive && *tz_datetime == *start_time)
+        };
+
+        // If start_time is the later of two instants sharing a wall-clock time, the first pass of
+        // the repeated wall-clock times after it still lies behind, and comes after every time
+        // preceding the repetition. The first pass is then only looked for here
+        let mut in_second_pass = false;
+        let mut first_pass = None;
+        if let LocalResult::Ambiguous(earliest, latest) =
+            originaltimezone.from_local_datetime(&naive_time)
+        {
+            // Compared by whole seconds, as the time searched from drops the fraction
+            if latest.timestamp() == start_time.timestamp() {
+                in_second_pass = true;
+                let overlap = latest.signed_duration_since(earliest);
+                let mut repeated_time = last_repeated_time(naive_time, overlap, &originaltimezone);
+                self.find_previous_naive_match(&mut repeated_time)?;
+                first_pass = self
+                    .resolve_local_time(repeated_time, &originaltimezone)?
+                    .into_iter()
+                    .rev()
+                    .find(is_before_start);
+            }
+        }
+
+        // Convert each match back to the original timezone, and return the last instant before
+        // start_time
+        let previous = loop {
+            if let Err(error) = self.find_previous_naive_match(&mut naive_time) {
+                break Err(error);
+            }
+            let earlier_pass = match originaltimezone.from_local_datetime(&naive_time) {
+                LocalResult::Ambiguous(earliest, _) if in_second_pass => Some(earliest),
+                _ => None,
+            };
+            if let Some(tz_datetime) = self
+                .resolve_local_time(naive_time, &originaltimezone)?
+                .into_iter()
+                .rev()
+                .filter(|tz_datetime| Some(tz_datetime) != earlier_pass.as_ref())
+                .find(is_before_start)
+            {
+                break Ok(tz_datetime);
+            }
+            naive_time = naive_time
+                .checked_sub_signed(Duration::seconds(1))
+                .ok_or(CronError::TimeSearchLimitExceeded)?;
+        };
+
+        match (previous, first_pass) {
+            (Ok(previous), Some(first_pass)) => Ok(previous.max(first_pass)),
+            (Err(CronError::TimeSearchLimitExceeded), Some(first_pass)) => Ok(first_pass),
+            (previous, _) => previous,
+        }
+    }
+
+    // Converts a wall-clock time to the instants it fires at, according to the daylight saving
+    // policies. Times skipped by a transition either snap forward to the end of the gap or are
+    // skipped, and times repeated by a transition fire at the first, last or both instants.
+    fn resolve_local_time<Tz: TimeZone>(
+        &self,
+        naive_time: NaiveDateTime,
+        timezone: &Tz,
+    ) -> Result<Vec<DateTime<Tz>>, CronError> {
+        Ok(match timezone.from_local_datetime(&naive_time) {
+            LocalResult::Single(tz_datetime) => vec![tz_datetime],
+            LocalResult::Ambiguous(earliest, latest) => match self.pattern.dst_overlap {
+                DstOverlapPolicy::FireFirst => vec![earliest],
+                DstOverlapPolicy::FireLast => vec![latest],
+                DstOverlapPolicy::FireBoth => vec![earliest, latest],
+            },
+            LocalResult::None => match self.pattern.dst_gap {
+                DstGapPolicy::Skip => vec![],
+                DstGapPolicy::SnapForward => vec![first_instant_at_or_after(naive_time, timezone)?],
+            },
+        })
+    }
+
+    /// Collects every occurrence within the minute, hour or day containing `time`.
+    ///
+    /// The unit is taken in local time, so the day containing `time` runs from its local
+    /// midnight to the next. Units shortened or lengthened by a daylight saving transition
+    /// cover the instants they actually contain, and a repeated hour is included as a whole.
+    /// A pattern not matching within the unit results in an empty vector.
+    ///
+    /// # Examples
     ///
-    /// for time in cron.clone().iter_from(time).take(5) {
-    ///     println!("{}", time);
-    /// }
     /// ```
+    /// use chrono::{TimeZone, Utc};
+    /// use croner::{Cron, TimeUnit};
     ///
-    /// # Parameters
+    /// let cron = Cron::new("*/20 * * * * *")
+    ///     .with_seconds_required()
+    ///     .parse()
+    ///     .expect("Couldn't parse cron string");
+    /// let time = Utc.with_ymd_and_hms(2024, 1, 1, 9, 30, 45).unwrap();
     ///
-    /// - `start_from`: A `DateTime<Tz>` that represents the starting point for the iterator.
+    /// let hits = cron.occurrences_in(&time, TimeUnit::Minute).unwrap();
+    /// assert_eq!(
+    ///     hits,
+    ///     vec![
+    ///         Utc.with_ymd_and_hms(2024, 1, 1, 9, 30, 0).unwrap(),
+    ///         Utc.with_ymd_and_hms(2024, 1, 1, 9, 30, 20).unwrap(),
+    ///         Utc.with_ymd_and_hms(2024, 1, 1, 9, 30, 40).unwrap(),
+    ///     ]
+    /// );
+    /// ```
+    pub fn occurrences_in<Tz>(
+        &self,
+        time: &DateTime<Tz>,
+        unit: TimeUnit,
+    ) -> Result<Vec<DateTime<Tz>>, CronError>
+    where
+        Tz: TimeZone,
+    {
+        let naive_time = time.naive_local();
+        let unit_start = match unit {
+            TimeUnit::Minute => {
+                naive_time
+                    .date()
+                    .and_hms_opt(naive_time.hour(), naive_time.minute(), 0)
+            }
+            TimeUnit::Hour => naive_time.date().and_hms_opt(naive_time.hour(), 0, 0),
+            TimeUnit::Day => naive_time.date().and_hms_opt(0, 0, 0),
+        }
+        .ok_or(CronError::InvalidTime)?;
+        let unit_end = unit_start
+            .checked_add_signed(match unit {
+                TimeUnit::Minute => Duration::minutes(1),
+                TimeUnit::Hour => Duration::hours(1),
+                TimeUnit::Day => Duration::days(1),
+            })
+            .ok_or(CronError::InvalidTime)?;
+
+        // Bound the search by the instants where the unit begins and where the next one begins
+        let timezone = time.timezone();
+        let start = first_instant_at_or_after(unit_start, &timezone)?;
+        let end = first_instant_at_or_after(unit_end, &timezone)? - Duration::nanoseconds(1);
+        self.matches_in(start..=end)
+    }
+
+    /// Returns the time left from `from` until the next occurrence of the pattern.
     ///
-    /// # Returns
+    /// The duration is the actual elapsed time between the two instants, so a daylight saving
+    /// transition in between is accounted for. An occurrence at `from` itself is not considered.
     ///
-    /// Returns a `CronIterator<Tz>` that can be used to iterate over scheduled times.
-    pub fn iter_from<Tz>(&self, start_from: DateTime<Tz>) -> CronIterator<Tz>
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::{Duration, TimeZone, Utc};
+    /// use croner::Cron;
+    ///
+    /// let cron = Cron::new("0 12 * * *").parse().expect("Couldn't parse cron string");
+    /// let time = Utc.with_ymd_and_hms(2024, 1, 1, 9, 30, 0).unwrap();
+    ///
+    /// assert_eq!(cron.time_until_next(&time).unwrap(), Duration::minutes(150));
+    /// ```
+    pub fn time_until_next<Tz>(&self, from: &DateTime<Tz>) -> Result<Duration, CronError>
     where
         Tz: TimeZone,
     {
-        CronIterator::new(self.clone(), start_from)
+        let next = self.find_next_occurrence(from, false)?;
+        Ok(next.signed_duration_since(from.clone()))
     }
 
-    /// Creates a `CronIterator` starting after the specified time.
+    /// Returns the time passed since the previous occurrence of the pattern, up to `from`.
     ///
-    /// This function will create an iterator that yields dates and times that
-    /// match a cron schedule, beginning after `start_after`. The iterator will
-    /// not yield the specified start time; it will yield times that come
-    /// after it according to the cron schedule.
+    /// Like `time_until_next`, the duration is the actual elapsed time, and an occurrence at
+    /// `from` itself is not considered.
+    pub fn time_since_previous<Tz>(&self, from: &DateTime<Tz>) -> Result<Duration, CronError>
+    where
+        Tz: TimeZone,
+    {
+        let previous = self.find_previous_occurrence(from, false)?;
+        Ok(from.clone().signed_duration_since(previous))
+    }
+
+    /// Checks whether the UTC offset changes between `from` and the next occurrence of the pattern.
+    ///
+    /// This indicates that a daylight saving transition lies in between, so the actual time until
+    /// the next occurrence differs from what the wall clock suggests.
     ///
     /// # Examples
     ///
     /// ```
-    /// use chrono::Utc;
+    /// use chrono::TimeZone;
+    /// use chrono_tz::Europe::Stockholm;
     /// use croner::Cron;
     ///
-    /// // Parse cron expression
-    /// let cron = Cron::new("* * * * *").parse().expect("Couldn't parse cron string");
+    /// let cron = Cron::new("0 12 * * *").parse().expect("Couldn't parse cron string");
+    /// // Clocks move forward one hour at 02:00 on 2024-03-31 in Stockholm
+    /// let time = Stockholm.with_ymd_and_hms(2024, 3, 30, 12, 0, 0).unwrap();
     ///
-    /// // Compare to time now
-    /// let time = Utc::now();
+    /// assert!(cron.next_crosses_dst(&time).unwrap());
+    /// ```
+    pub fn next_crosses_dst<Tz>(&self, from: &DateTime<Tz>) -> Result<bool, CronError>
+    where
+        Tz: TimeZone,
+    {
+        let next = self.find_next_occurrence(from, false)?;
+        Ok(from.offset().fix() != next.offset().fix())
+    }
+
+    /// Finds the next `n` occurrences of the pattern, starting from `start_time`.
     ///
-    /// // Get next 5 matches using iter_from
-    /// println!("Finding matches of pattern '{}' starting from {}:", cron.pattern.to_string(), time);
+    /// Unlike collecting from `iter_from` or `iter_after`, which silently stop when no further
+    /// occurrence can be found, this returns an error if fewer than `n` occurrences exist.
     ///
-    /// for time in cron.clone().iter_after(time).take(5) {
-    ///     println!("{}", time);
-    /// }
-    ///  
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::{TimeZone, Utc};
+    /// use croner::Cron;
+    ///
+    /// let cron = Cron::new("0 12 * * *").parse().expect("Couldn't parse cron string");
+    /// let time = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+    ///
+    /// let next_runs = cron.find_next_n(&time, 3, false).unwrap();
+    /// assert_eq!(next_runs.len(), 3);
     /// ```
     ///
     /// # Parameters
     ///
-    /// - `start_after`: A `DateTime<Tz>` that represents the starting point for the iterator.
+    /// - `start_time`: A `DateTime<Tz>` that represents the starting point for the search.
+    /// - `n`: The number of occurrences to find.
+    /// - `inclusive`: Whether `start_time` itself may be the first occurrence.
     ///
-    /// # Returns
+    /// # Errors
     ///
-    /// Returns a `CronIterator<Tz>` that can be used to iterate over scheduled times.
-    pub fn iter_after<Tz: TimeZone>(&self, start_after: DateTime<Tz>) -> CronIterator<Tz>
+    /// Returns the first error encountered while searching, such as `CronError::TimeSearchLimitExceeded`.
+    pub fn find_next_n<Tz>(
+        &self,
+        start_time: &DateTime<Tz>,
+        n: usize,
+        inclusive: bool,
+    ) -> Result<Vec<DateTime<Tz>>, CronError>
     where
         Tz: TimeZone,
     {
-        let start_from = start_after
-            .checked_add_signed(Duration::seconds(1))
-            .expect("Invalid date encountered when adding one second");
-        CronIterator::new(self.clone(), start_from)
+        self.find_n(start_time, n, inclusive, SearchDirection::Forward)
     }
 
-    // Internal functions to check for the next matching month/day/hour/minute/second and return the updated time.
-    fn find_next_matching_month(
+    /// Snaps `time` to the nearest occurrence at or after it, or at or before it when searching
+    /// backward. A `time` that matches is returned as is.
+    ///
+    /// This is `find_next_occurrence` or `find_previous_occurrence` with `inclusive` set, which
+    /// is what aligning timestamps to schedule boundaries, like log buckets, needs. The
+    /// exclusive searches instead always move away from a matching `time`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::{TimeZone, Utc};
+    /// use croner::{Cron, SearchDirection};
+    ///
+    /// let cron = Cron::new("*/15 * * * *").parse().expect("Couldn't parse cron string");
+    /// let time = Utc.with_ymd_and_hms(2024, 1, 1, 10, 7, 30).unwrap();
+    ///
+    /// let bucket_start = cron.align(&time, SearchDirection::Backward).unwrap();
+    /// let bucket_end = cron.align(&time, SearchDirection::Forward).unwrap();
+    /// assert_eq!(bucket_start, Utc.with_ymd_and_hms(2024, 1, 1, 10, 0, 0).unwrap());
+    /// assert_eq!(bucket_end, Utc.with_ymd_and_hms(2024, 1, 1, 10, 15, 0).unwrap());
+    /// ```
+    pub fn align<Tz>(
         &self,
-        current_time: &mut NaiveDateTime,
-    ) -> Result<bool, CronError> {
-        let mut incremented = false;
-        while !self.pattern.month_match(current_time.month())? {
-            increment_time_component(current_time, TimeComponent::Month)?;
-            incremented = true;
-        }
-        Ok(incremented)
+        time: &DateTime<Tz>,
+        direction: SearchDirection,
+    ) -> Result<DateTime<Tz>, CronError>
+    where
+        Tz: TimeZone,
+    {
+        self.find_nth_occurrence(time, 0, true, direction)
     }
 
-    fn find_next_matching_day(&self, current_time: &mut NaiveDateTime) -> Result<bool, CronError> {
-        let mut incremented = false;
-        while !self.pattern.day_match(
-            current_time.year(),
-            current_time.month(),
-            current_time.day(),
+    /// Finds the occurrence `k` steps away from `start_time`, where `k = 0` is the first
+    /// occurrence found, like `Iterator::nth`.
+    ///
+    /// Repeated times of a daylight saving overlap are counted according to the
+    /// `DstOverlapPolicy` in use, the same way iterating does. Unlike `iter_after(..).nth(k)`,
+    /// errors are returned rather than ending the search.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::{TimeZone, Utc};
+    /// use croner::{Cron, SearchDirection};
+    ///
+    /// let cron = Cron::new("0 12 * * *").parse().expect("Couldn't parse cron string");
+    /// let time = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+    ///
+    /// let third = cron.find_nth_occurrence(&time, 2, false, SearchDirection::Forward).unwrap();
+    /// assert_eq!(third, Utc.with_ymd_and_hms(2024, 1, 3, 12, 0, 0).unwrap());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns `CronError::TimeSearchLimitExceeded` if the year limits are reached before the
+    /// occurrence, or the first other error encountered while searching.
+    pub fn find_nth_occurrence<Tz>(
+        &self,
+        start_time: &DateTime<Tz>,
+        k: usize,
+        inclusive: bool,
+        direction: SearchDirection,
+    ) -> Result<DateTime<Tz>, CronError>
+    where
+        Tz: TimeZone,
+    {
+        let find = |time: &DateTime<Tz>, inclusive| match direction {
+            SearchDirection::Forward => self.find_next_occurrence(time, inclusive),
+            SearchDirection::Backward => self.find_previous_occurrence(time, inclusive),
+        };
+        let mut current_time = find(start_time, inclusive)?;
+        for _ in 0..k {
+            current_time = find(&current_time, false)?;
+        }
+        Ok(current_time)
+    }
+
+    /// Finds the previous `n` occurrences of the pattern before `start_time`, latest first.
+    ///
+    /// The backward counterpart of `find_next_n`, returning an error if fewer than `n`
+    /// occurrences exist instead of silently returning fewer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::{TimeZone, Utc};
+    /// use croner::Cron;
+    ///
+    /// let cron = Cron::new("0 12 * * *").parse().expect("Couldn't parse cron string");
+    /// let time = Utc.with_ymd_and_hms(2024, 1, 10, 0, 0, 0).unwrap();
+    ///
+    /// let last_runs = cron.find_previous_n(&time, 3, false).unwrap();
+    /// assert_eq!(last_runs[0], Utc.with_ymd_and_hms(2024, 1, 9, 12, 0, 0).unwrap());
+    /// assert_eq!(last_runs.len(), 3);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns `CronError::TimeSearchLimitExceeded` if the search reaches the year 1 before
+    /// finding `n` occurrences, or the first other error encountered while searching.
+    pub fn find_previous_n<Tz>(
+        &self,
+        start_time: &DateTime<Tz>,
+        n: usize,
+        inclusive: bool,
+    ) -> Result<Vec<DateTime<Tz>>, CronError>
+    where
+        Tz: TimeZone,
+    {
+        self.find_n(start_time, n, inclusive, SearchDirection::Backward)
+    }
+
+    // Collects the first n occurrences found from start_time in the given direction, failing
+    // if fewer than n exist
+    fn find_n<Tz>(
+        &self,
+        start_time: &DateTime<Tz>,
+        n: usize,
+        inclusive: bool,
+        direction: SearchDirection,
+    ) -> Result<Vec<DateTime<Tz>>, CronError>
+    where
+        Tz: TimeZone,
+    {
+        let mut occurrences = Vec::with_capacity(n);
+        let mut current_time = start_time.clone();
+        let mut inclusive = inclusive;
+        while occurrences.len() < n {
+            let found_time = match direction {
+                SearchDirection::Forward => self.find_next_occurrence(&current_time, inclusive),
+                SearchDirection::Backward => {
+                    self.find_previous_occurrence(&current_time, inclusive)
+                }
+            }?;
+            occurrences.push(found_time.clone());
+            current_time = found_time;
+            inclusive = false;
+        }
+        Ok(occurrences)
+    }
+
+    /// Creates a `CronIterator` starting from the specified time.
+    ///
+    /// This function will create an iterator that yields dates and times that
+    /// match a cron schedule, beginning at `start_from`. The iterator will
+    /// begin at the specified start time if it matches.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::Utc;
+    /// use croner::Cron;
+    ///
+    /// // Parse cron expression
+    /// let cron = Cron::new("* * * * *").parse().expect("Couldn't parse cron string");
+    ///
+    /// // Compare to time now
+    /// let time = Utc::now();
+    ///
+    /// // Get next 5 matches using iter_from
+    /// println!("Finding matches of pattern '{}' starting from {}:", cron.pattern().to_string(), time);
+    ///
+    /// for time in cron.clone().iter_from(time).take(5) {
+    ///     println!("{}", time);
+    /// }
+    /// ```
+    ///
+    /// # Parameters
+    ///
+    /// - `start_from`: A `DateTime<Tz>` that represents the starting point for the iterator.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `CronIterator<Tz>` that can be used to iterate over scheduled times.
+    pub fn iter_from<Tz>(&self, start_from: DateTime<Tz>) -> CronIterator<Tz>
+    where
+        Tz: TimeZone,
+    {
+        CronIterator::new(self.clone(), start_from)
+    }
+
+    /// Creates a `CronIterator` starting after the specified time.
+    ///
+    /// This function will create an iterator that yields dates and times that
+    /// match a cron schedule, beginning after `start_after`. The iterator will
+    /// not yield the specified start time; it will yield times that come
+    /// after it according to the cron schedule.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::Utc;
+    /// use croner::Cron;
+    ///
+    /// // Parse cron expression
+    /// let cron = Cron::new("* * * * *").parse().expect("Couldn't parse cron string");
+    ///
+    /// // Compare to time now
+    /// let time = Utc::now();
+    ///
+    /// // Get next 5 matches using iter_from
+    /// println!("Finding matches of pattern '{}' starting from {}:", cron.pattern().to_string(), time);
+    ///
+    /// for time in cron.clone().iter_after(time).take(5) {
+    ///     println!("{}", time);
+    /// }
+    ///  
+    /// ```
+    ///
+    /// # Parameters
+    ///
+    /// - `start_after`: A `DateTime<Tz>` that represents the starting point for the iterator.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `CronIterator<Tz>` that can be used to iterate over scheduled times.
+    pub fn iter_after<Tz>(&self, start_after: DateTime<Tz>) -> CronIterator<Tz>
+    where
+        Tz: TimeZone,
+    {
+        let start_from = start_after
+            .checked_add_signed(Duration::seconds(1))
+            .expect("Invalid date encountered when adding one second");
+        CronIterator::new(self.clone(), start_from)
+    }
+
+    /// Creates a `CronIterator` like `iter_after`, limiting the total search work of the
+    /// whole iteration.
+    ///
+    /// Each pass over the time components while searching for an occurrence uses up one of
+    /// `max_searches`, and the iteration ends once they run out. This keeps the cost of
+    /// iterating user-supplied patterns that rarely or never match predictable.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::{TimeZone, Utc};
+    /// use croner::Cron;
+    ///
+    /// // February 29th on a Monday, only every 28 years or so
+    /// let cron = Cron::new("0 0 29 2 MON")
+    ///     .with_dom_and_dow()
+    ///     .parse()
+    ///     .expect("Couldn't parse cron string");
+    /// let start = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+    ///
+    /// assert_eq!(cron.bounded_iter_after(start, 10).count(), 0);
+    /// ```
+    pub fn bounded_iter_after<Tz>(
+        &self,
+        start_after: DateTime<Tz>,
+        max_searches: u64,
+    ) -> CronIterator<Tz>
+    where
+        Tz: TimeZone,
+    {
+        self.iter_after(start_after).with_max_searches(max_searches)
+    }
+
+    /// Creates an iterator like `try_iter_after`, yielding each occurrence together with a
+    /// `MatchReport` of the rules that made it match.
+    ///
+    /// This is useful for understanding complex schedules using `L`, `#` or `W`. Occurrences
+    /// moved forward out of a daylight saving gap are reported by the rules matching the skipped
+    /// wall-clock time, as the time they fire at does not match the pattern itself.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::Utc;
+    /// use croner::Cron;
+    ///
+    /// let cron = Cron::new("0 0 L * FRI").parse().expect("Couldn't parse cron string");
+    ///
+    /// for occurrence in cron.iter_after_reported(Utc::now()).take(5) {
+    ///     let (time, report) = occurrence.expect("Couldn't find the next occurrence");
+    ///     let reason = if report.last_day_of_month { "last day of month" } else { "friday" };
+    ///     println!("{} ({})", time, reason);
+    /// }
+    /// ```
+    pub fn iter_after_reported<Tz>(
+        &self,
+        start_after: DateTime<Tz>,
+    ) -> impl Iterator<Item = Result<(DateTime<Tz>, MatchReport), CronError>>
+    where
+        Tz: TimeZone,
+    {
+        let cron = self.clone();
+        self.try_iter_after(start_after).map(move |time| {
+            let time = time?;
+            let report = cron.occurrence_report(time.naive_local())?;
+            Ok((time, report))
+        })
+    }
+
+    // Reports the rules of the wall-clock time an occurrence was found at. Occurrences moved
+    // forward out of a DST gap do not match, so the latest matching time before them is used
+    fn occurrence_report(&self, naive_time: NaiveDateTime) -> Result<MatchReport, CronError> {
+        let mut matched_time = naive_time;
+        if !self.is_naive_time_matching(&matched_time)? {
+            matched_time = matched_time
+                .checked_sub_signed(Duration::seconds(1))
+                .ok_or(CronError::TimeSearchLimitExceeded)?;
+            self.find_previous_naive_match(&mut matched_time)?;
+        }
+        self.pattern
+            .day_match_report(
+                matched_time.year(),
+                matched_time.month(),
+                matched_time.day(),
+            )?
+            .ok_or(CronError::TimeSearchLimitExceeded)
+    }
+
+    /// Creates a `CronTryIterator` starting from the specified time.
+    ///
+    /// Works like `iter_from`, but yields `Result`s so that errors are not silently swallowed.
+    /// Running out of occurrences ends the iteration, while any other error is yielded as
+    /// `Err` before the iteration ends.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::Utc;
+    /// use croner::Cron;
+    ///
+    /// let cron = Cron::new("* * * * *").parse().expect("Couldn't parse cron string");
+    ///
+    /// for time in cron.try_iter_from(Utc::now()).take(5) {
+    ///     match time {
+    ///         Ok(time) => println!("{}", time),
+    ///         Err(e) => eprintln!("Scheduling failed: {}", e),
+    ///     }
+    /// }
+    /// ```
+    pub fn try_iter_from<Tz>(&self, start_from: DateTime<Tz>) -> CronTryIterator<Tz>
+    where
+        Tz: TimeZone,
+    {
+        CronTryIterator::new(self.clone(), start_from, true)
+    }
+
+    /// Creates a `CronTryIterator` starting after the specified time.
+    ///
+    /// Works like `iter_after`, but yields `Result`s, see `try_iter_from`.
+    pub fn try_iter_after<Tz>(&self, start_after: DateTime<Tz>) -> CronTryIterator<Tz>
+    where
+        Tz: TimeZone,
+    {
+        CronTryIterator::new(self.clone(), start_after, false)
+    }
+
+    /// Creates a `CronBoundedIterator` yielding every occurrence between `start` and `end`.
+    ///
+    /// Both ends of the interval are inclusive, so `start` and `end` are yielded if they
+    /// match the pattern. If `end` is before `start`, the iterator yields nothing. The
+    /// occurrences can also be walked from `end` backwards, using `rev` or `next_back`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::{TimeZone, Utc};
+    /// use croner::Cron;
+    ///
+    /// let cron = Cron::new("0 0 * * *").parse().expect("Couldn't parse cron string");
+    /// let start = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+    /// let end = Utc.with_ymd_and_hms(2024, 1, 7, 0, 0, 0).unwrap();
+    ///
+    /// assert_eq!(cron.iter_between(start, end).count(), 7);
+    /// ```
+    ///
+    /// # Parameters
+    ///
+    /// - `start`: A `DateTime<Tz>` that represents the start of the interval.
+    /// - `end`: A `DateTime<Tz>` that represents the end of the interval.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `CronBoundedIterator<Tz>` that stops once `end` is passed.
+    pub fn iter_between<Tz>(
+        &self,
+        start: DateTime<Tz>,
+        end: DateTime<Tz>,
+    ) -> CronBoundedIterator<Tz>
+    where
+        Tz: TimeZone,
+    {
+        CronIterator::new(self.clone(), start).with_end(end)
+    }
+
+    /// Collects every occurrence within an inclusive range of times.
+    ///
+    /// This is the fallible counterpart of `iter_between`: running out of occurrences
+    /// before the end of the range simply ends the result, while other errors are returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::{TimeZone, Utc};
+    /// use croner::Cron;
+    ///
+    /// let cron = Cron::new("0 0 * * *").parse().expect("Couldn't parse cron string");
+    /// let jan1 = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+    /// let jan5 = Utc.with_ymd_and_hms(2024, 1, 5, 0, 0, 0).unwrap();
+    ///
+    /// assert_eq!(cron.matches_in(jan1..=jan5).unwrap().len(), 5);
+    /// ```
+    pub fn matches_in<Tz>(
+        &self,
+        range: RangeInclusive<DateTime<Tz>>,
+    ) -> Result<Vec<DateTime<Tz>>, CronError>
+    where
+        Tz: TimeZone,
+    {
+        let (start, end) = range.into_inner();
+        let mut matches = Vec::new();
+        let mut inclusive = true;
+        let mut current_time = start;
+        loop {
+            let next_time = match self.find_next_occurrence(&current_time, inclusive) {
+                Ok(next_time) => next_time,
+                Err(CronError::TimeSearchLimitExceeded) => break,
+                Err(e) => return Err(e),
+            };
+            if next_time > end {
+                break;
+            }
+            matches.push(next_time.clone());
+            current_time = next_time;
+            inclusive = false;
+        }
+        Ok(matches)
+    }
+
+    /// Checks whether the pattern fires at all between `start` and `end`, both included.
+    ///
+    /// Stops at the first occurrence found, which is cheaper than `count_between` for dense
+    /// patterns.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::{TimeZone, Utc};
+    /// use croner::Cron;
+    ///
+    /// let cron = Cron::new("0 0 29 2 *").parse().expect("Couldn't parse cron string");
+    /// let feb2023 = Utc.with_ymd_and_hms(2023, 2, 1, 0, 0, 0).unwrap();
+    /// let mar2023 = Utc.with_ymd_and_hms(2023, 3, 1, 0, 0, 0).unwrap();
+    ///
+    /// assert!(!cron.matches_any_in(&feb2023, &mar2023).unwrap());
+    /// ```
+    pub fn matches_any_in<Tz>(
+        &self,
+        start: &DateTime<Tz>,
+        end: &DateTime<Tz>,
+    ) -> Result<bool, CronError>
+    where
+        Tz: TimeZone,
+    {
+        if end < start {
+            return Ok(false);
+        }
+
+        // The search ends once it passes the wall-clock time of end. When end is the later pass
+        // of a repeated wall-clock time, the earlier pass of the times following it is before end
+        let mut until = end.naive_local();
+        if let LocalResult::Ambiguous(earliest, latest) = end.timezone().from_local_datetime(&until)
+        {
+            if latest == *end {
+                until += latest.signed_duration_since(earliest);
+            }
+        }
+        let mut searches_left = u64::MAX;
+        match self.find_next_occurrence_limited(start, true, Some(&until), &mut searches_left) {
+            Ok(next_time) => Ok(next_time <= *end),
+            Err(CronError::TimeSearchLimitExceeded) => Ok(false),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Checks whether two crons fire at the same times on every day from `start` to `end`.
+    ///
+    /// Equality through `PartialEq` compares normalized patterns, which can not prove every
+    /// equivalence, like `L` and `31` in a month with 31 days. This instead compares the matching
+    /// times of day once and then evaluates both crons on each day of the window, so the cost
+    /// grows with the number of days in the window rather than the number of occurrences. Only
+    /// the times within the window are compared, and the daylight saving policies have to be
+    /// equal as well.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::{TimeZone, Utc};
+    /// use croner::Cron;
+    ///
+    /// let weekdays = Cron::new("0 9 * * MON-FRI").parse().expect("Couldn't parse cron string");
+    /// let listed = Cron::new("0 9 * * 1,2,3,4,5").parse().expect("Couldn't parse cron string");
+    /// let start = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+    /// let end = Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap();
+    ///
+    /// assert!(weekdays.is_equivalent_to(&listed, &start, &end).unwrap());
+    /// ```
+    pub fn is_equivalent_to<Tz>(
+        &self,
+        other: &Cron,
+        start: &DateTime<Tz>,
+        end: &DateTime<Tz>,
+    ) -> Result<bool, CronError>
+    where
+        Tz: TimeZone,
+    {
+        let (this, that) = (&self.pattern, &other.pattern);
+        if this.dst_gap != that.dst_gap || this.dst_overlap != that.dst_overlap {
+            return Ok(false);
+        }
+        let same_times = this.seconds.get_set_values(ALL_BIT)
+            == that.seconds.get_set_values(ALL_BIT)
+            && this.minutes.get_set_values(ALL_BIT) == that.minutes.get_set_values(ALL_BIT)
+            && this.hours.get_set_values(ALL_BIT) == that.hours.get_set_values(ALL_BIT);
+
+        let day_matches = |pattern: &CronPattern, date: NaiveDate| -> Result<bool, CronError> {
+            Ok(pattern.month_match(date.month())?
+                && pattern.day_match(date.year(), date.month(), date.day())?)
+        };
+        let time_matches = |pattern: &CronPattern, time: NaiveTime| -> Result<bool, CronError> {
+            Ok(pattern.hour_match(time.hour())?
+                && pattern.minute_match(time.minute())?
+                && pattern.second_match(time.second())?)
+        };
+
+        // Only whole seconds can match, so round the start up and the end down
+        let mut from = start.naive_local();
+        if from.nanosecond() > 0 {
+            from = from
+                .with_nanosecond(0)
+                .and_then(|from| from.checked_add_signed(Duration::seconds(1)))
+                .ok_or(CronError::InvalidTime)?;
+        }
+        let to = end
+            .with_timezone(&start.timezone())
+            .naive_local()
+            .with_nanosecond(0)
+            .ok_or(CronError::InvalidTime)?;
+
+        for date in from
+            .date()
+            .iter_days()
+            .take_while(|date| *date <= to.date())
+        {
+            let (this_matches, that_matches) = (day_matches(this, date)?, day_matches(that, date)?);
+            if this_matches == that_matches && (!this_matches || same_times) {
+                continue;
+            }
+
+            // Days at the ends of the window are only compared within it
+            let day_from = if date == from.date() {
+                from.time()
+            } else {
+                NaiveTime::MIN
+            };
+            let day_to = if date == to.date() {
+                to.time()
+            } else {
+                at_time(date, 23, 59, 59)?.time()
+            };
+            let mut time = day_from;
+            loop {
+                let this_fires = this_matches && time_matches(this, time)?;
+                if this_fires != (that_matches && time_matches(that, time)?) {
+                    return Ok(false);
+                }
+                if time >= day_to {
+                    break;
+                }
+                time += Duration::seconds(1);
+            }
+        }
+        Ok(true)
+    }
+
+    /// Counts the occurrences between `start` and `end` without collecting them.
+    ///
+    /// If `inclusive` is `true`, occurrences at `start` and `end` themselves are counted.
+    ///
+    /// Matching times are counted per day rather than found one by one, so dense patterns like
+    /// `* * * * * *` are counted quickly over long ranges. On days with a daylight saving
+    /// transition, each matching wall-clock time is checked on its own and counted according
+    /// to the `DstGapPolicy` and `DstOverlapPolicy` in use.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::{TimeZone, Utc};
+    /// use croner::Cron;
+    ///
+    /// let cron = Cron::new("* * * * *").parse().expect("Couldn't parse cron string");
+    /// let start = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+    /// let end = Utc.with_ymd_and_hms(2024, 1, 31, 23, 59, 0).unwrap();
+    ///
+    /// assert_eq!(cron.count_between(&start, &end, true).unwrap(), 31 * 24 * 60);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns `CronError::TimeSearchLimitExceeded` if `end` is beyond the year 5000.
+    pub fn count_between<Tz>(
+        &self,
+        start: &DateTime<Tz>,
+        end: &DateTime<Tz>,
+        inclusive: bool,
+    ) -> Result<u64, CronError>
+    where
+        Tz: TimeZone,
+    {
+        if end < start {
+            return Ok(0);
+        }
+        let timezone = start.timezone();
+        let end_local = end.with_timezone(&timezone).naive_local();
+        if end_local.year() >= YEAR_UPPER_LIMIT {
+            return Err(CronError::TimeSearchLimitExceeded);
+        }
+
+        // Only whole seconds can match, so round the start up and the end down
+        let mut from = start.naive_local();
+        if from.nanosecond() > 0 {
+            from = from
+                .with_nanosecond(0)
+                .and_then(|from| from.checked_add_signed(Duration::seconds(1)))
+                .ok_or(CronError::InvalidTime)?;
+        }
+        let to = end_local.with_nanosecond(0).ok_or(CronError::InvalidTime)?;
+
+        let mut count = 0;
+        for date in from
+            .date()
+            .iter_days()
+            .take_while(|date| *date <= to.date())
+        {
+            if !self.pattern.month_match(date.month())?
+                || !self
+                    .pattern
+                    .day_match(date.year(), date.month(), date.day())?
+            {
+                continue;
+            }
+            let day_from = if date == from.date() {
+                from.time()
+            } else {
+                NaiveTime::MIN
+            };
+            let day_to = if date == to.date() {
+                to.time()
+            } else {
+                at_time(date, 23, 59, 59)?.time()
+            };
+            count += if has_constant_offset(&timezone, date) {
+                self.count_times_of_day(day_from, day_to)?
+            } else {
+                self.count_instants_of_transition_day(date, start, end)?
+            };
+        }
+
+        // Occurrences at start and end were counted above, unless they fall between whole seconds
+        if !inclusive {
+            if start.nanosecond() == 0 && self.is_time_matching(start)? {
+                count -= 1;
+            }
+            if end != start && end.nanosecond() == 0 && self.is_time_matching(end)? {
+                count -= 1;
+            }
+        }
+        Ok(count)
+    }
+
+    // Counts the matching times of day between from and to, both inclusive
+    fn count_times_of_day(&self, from: NaiveTime, to: NaiveTime) -> Result<u64, CronError> {
+        let seconds_per_minute = count_matching(0..=59, |s| self.pattern.second_match(s))?;
+        let minutes_per_hour = count_matching(0..=59, |m| self.pattern.minute_match(m))?;
+
+        let mut count = 0;
+        for hour in from.hour()..=to.hour() {
+            if !self.pattern.hour_match(hour)? {
+                continue;
+            }
+            // Hours strictly between the ends are covered entirely
+            if hour != from.hour() && hour != to.hour() {
+                count += minutes_per_hour * seconds_per_minute;
+                continue;
+            }
+            let first_minute = if hour == from.hour() {
+                from.minute()
+            } else {
+                0
+            };
+            let last_minute = if hour == to.hour() { to.minute() } else { 59 };
+            for minute in first_minute..=last_minute {
+                if !self.pattern.minute_match(minute)? {
+                    continue;
+                }
+                let first_second = if (hour, minute) == (from.hour(), from.minute()) {
+                    from.second()
+                } else {
+                    0
+                };
+                let last_second = if (hour, minute) == (to.hour(), to.minute()) {
+                    to.second()
+                } else {
+                    59
+                };
+                count +=
+                    count_matching(first_second..=last_second, |s| self.pattern.second_match(s))?;
+            }
+        }
+        Ok(count)
+    }
+
+    // Counts the instants of a day with a daylight saving transition that fall between start and
+    // end, both inclusive. Matching times are checked one by one and resolved according to the
+    // daylight saving policies, so times snapped to the end of a gap are only counted once.
+    fn count_instants_of_transition_day<Tz: TimeZone>(
+        &self,
+        date: NaiveDate,
+        start: &DateTime<Tz>,
+        end: &DateTime<Tz>,
+    ) -> Result<u64, CronError> {
+        let timezone = start.timezone();
+        let mut instants = Vec::new();
+        for hour in 0..=23 {
+            if !self.pattern.hour_match(hour)? {
+                continue;
+            }
+            for minute in 0..=59 {
+                if !self.pattern.minute_match(minute)? {
+                    continue;
+                }
+                for second in 0..=59 {
+                    if !self.pattern.second_match(second)? {
+                        continue;
+                    }
+                    let time = at_time(date, hour, minute, second)?;
+                    instants.extend(
+                        self.resolve_local_time(time, &timezone)?
+                            .into_iter()
+                            .filter(|instant| instant >= start && instant <= end),
+                    );
+                }
+            }
+        }
+        instants.sort();
+        instants.dedup();
+        Ok(instants.len() as u64)
+    }
+
+    /// Translates the pattern into the calendar event syntax used by `OnCalendar=` in
+    /// systemd timer units, like `Mon..Fri *-*-* 09:00:00`.
+    ///
+    /// Lists, ranges, steps and `L` or `L-n` as the only day of month are supported. Patterns
+    /// using `W`, `#` or `L` in the day of week field, or restricting both the day of month and
+    /// the day of week without `with_dom_and_dow`, have no systemd equivalent and return an error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use croner::Cron;
+    ///
+    /// let cron = Cron::new("0 9 * * MON-FRI").parse().expect("Couldn't parse cron string");
+    /// assert_eq!(cron.to_systemd_oncalendar().unwrap(), "Mon..Fri *-*-* 09:00:00");
+    /// ```
+    pub fn to_systemd_oncalendar(&self) -> Result<String, CronError> {
+        systemd::to_oncalendar(&self.pattern)
+    }
+
+    /// Translates the pattern into a Quartz cron expression, like `0 15 10 ? * 2-6`.
+    ///
+    /// Weekdays are shifted to the Quartz numbering where 1 is Sunday and 7 is Saturday, and `?`
+    /// is placed in the day field that is not restricted. `L`, `L-n`, `W`, `#` and weekday `L`
+    /// are supported as the only entry of their field. Patterns restricting both the day of month
+    /// and the day of week have no Quartz equivalent, the returned error lists every feature
+    /// that can not be expressed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use croner::Cron;
+    ///
+    /// let cron = Cron::new("15 10 * * MON-FRI").parse().expect("Couldn't parse cron string");
+    /// assert_eq!(cron.to_quartz().unwrap(), "0 15 10 ? * 2-6");
+    /// ```
+    pub fn to_quartz(&self) -> Result<String, CronError> {
+        quartz::to_quartz(&self.pattern)
+    }
+
+    /// Creates a `Cron` from a calendar event as used by `OnCalendar=` in systemd timer units,
+    /// like `Mon..Fri 09:00` or `*-*-01 00:00:00`.
+    ///
+    /// Weekdays, lists, ranges, repetitions, `~` offsets from the end of the month and
+    /// shorthands like `daily` are supported. Explicit years, sub-second precision and
+    /// time zones have no cron equivalent and return an error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use croner::Cron;
+    ///
+    /// let cron = Cron::from_systemd("Mon..Fri 09:00").expect("Couldn't translate calendar event");
+    /// assert_eq!(cron.to_string(), "0 0 9 * * 1-5");
+    /// ```
+    pub fn from_systemd(oncalendar: &str) -> Result<Cron, CronError> {
+        systemd::from_oncalendar(oncalendar)
+    }
+
+    /// Returns the fixed interval after which the schedule repeats itself, if there is one.
+    ///
+    /// Patterns restricted to certain weekdays repeat weekly, and patterns firing every day
+    /// repeat daily or more often, like every 15 minutes for `*/15 * * * *`. Patterns depending
+    /// on the calendar, through the day of month, month, `L`, `#` or `W`, have no fixed period
+    /// since months and years vary in length, and return `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::Duration;
+    /// use croner::Cron;
+    ///
+    /// let cron = Cron::new("0 0 * * MON").parse().expect("Couldn't parse cron string");
+    /// assert_eq!(cron.period(), Some(Duration::days(7)));
+    ///
+    /// let cron = Cron::new("0 0 1 * *").parse().expect("Couldn't parse cron string");
+    /// assert_eq!(cron.period(), None);
+    /// ```
+    pub fn period(&self) -> Option<Duration> {
+        let pattern = &self.pattern;
+        let all_months = pattern.months.count_set_values(ALL_BIT) == 12;
+        let weekdays = pattern.days_of_week.get_set_values(ALL_BIT);
+        let all_weekdays = (0..=6).all(|weekday| weekdays.contains(&weekday));
+        let special_weekdays = !pattern
+            .days_of_week
+            .get_set_values(NTH_ALL | LAST_BIT)
+            .is_empty();
+        if !all_months || !pattern.star_dom() || special_weekdays {
+            return None;
+        }
+        if !pattern.star_dow() && !all_weekdays {
+            return Some(Duration::days(7));
+        }
+
+        // Every day matches, find the smallest shift that maps the matching times of day onto
+        // themselves. Such a shift always divides a day.
+        const SECONDS_PER_DAY: u32 = 24 * 60 * 60;
+        let mut matching = vec![false; SECONDS_PER_DAY as usize];
+        let minutes = pattern.minutes.get_set_values(ALL_BIT);
+        let seconds = pattern.seconds.get_set_values(ALL_BIT);
+        for hour in pattern.hours.get_set_values(ALL_BIT) {
+            for &minute in &minutes {
+                for &second in &seconds {
+                    let time = u32::from(hour) * 3600 + u32::from(minute) * 60 + u32::from(second);
+                    matching[time as usize] = true;
+                }
+            }
+        }
+        // u32::is_multiple_of needs Rust 1.87, newer than what the crate builds with
+        #[allow(unknown_lints, clippy::manual_is_multiple_of)]
+        let period = (1..=SECONDS_PER_DAY)
+            .filter(|shift| SECONDS_PER_DAY % *shift == 0)
+            .find(|shift| {
+                (0..SECONDS_PER_DAY).all(|time| {
+                    matching[time as usize] == matching[((time + shift) % SECONDS_PER_DAY) as usize]
+                })
+            })?;
+        Some(Duration::seconds(i64::from(period)))
+    }
+
+    /// Returns `true` if consecutive occurrences are always the same time apart, going by the
+    /// wall clock.
+    ///
+    /// A step that does not divide the field size is not uniform, `*/7` in the seconds field
+    /// fires at 0, 7, ..., 56 and then 4 seconds later at 0 of the next minute. Patterns
+    /// without a fixed `period`, like monthly schedules, are never uniform.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use croner::Cron;
+    ///
+    /// let cron = Cron::new("*/15 * * * *").parse().expect("Couldn't parse cron string");
+    /// assert!(cron.interval_is_uniform());
+    ///
+    /// let cron = Cron::new("*/7 * * * *").parse().expect("Couldn't parse cron string");
+    /// assert!(!cron.interval_is_uniform());
+    /// ```
+    pub fn interval_is_uniform(&self) -> bool {
+        let Some(period) = self.period() else {
+            return false;
+        };
+        let pattern = &self.pattern;
+        let times_per_day = [&pattern.hours, &pattern.minutes, &pattern.seconds]
+            .iter()
+            .map(|component| i64::from(component.count_set_values(ALL_BIT)))
+            .product::<i64>();
+
+        // Uniform schedules fire exactly once per period
+        if period == Duration::days(7) {
+            times_per_day == 1 && pattern.days_of_week.count_set_values(ALL_BIT) == 1
+        } else {
+            times_per_day * period.num_seconds() == Duration::days(1).num_seconds()
+        }
+    }
+
+    /// Returns the smallest gap between two consecutive occurrences of the pattern.
+    ///
+    /// Useful for rejecting schedules firing more often than allowed. The gap is measured in
+    /// wall-clock time, so around a daylight saving transition the actual time between two
+    /// occurrences can be shorter or longer. Returns `None` if the pattern never fires.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::Duration;
+    /// use croner::Cron;
+    ///
+    /// let cron = Cron::new("*/5 * * * *").parse().expect("Couldn't parse cron string");
+    /// assert_eq!(cron.min_interval(), Some(Duration::minutes(5)));
+    ///
+    /// // Irregular schedules report their shortest gap, from 23:00 to 00:00 here
+    /// let cron = Cron::new("0 0,23 * * *").parse().expect("Couldn't parse cron string");
+    /// assert_eq!(cron.min_interval(), Some(Duration::hours(1)));
+    /// ```
+    pub fn min_interval(&self) -> Option<Duration> {
+        const SECONDS_PER_DAY: i64 = 24 * 60 * 60;
+        let pattern = &self.pattern;
+
+        // The matching times of day in seconds, in ascending order
+        let minutes = pattern.minutes.get_set_values(ALL_BIT);
+        let seconds = pattern.seconds.get_set_values(ALL_BIT);
+        let mut times = Vec::new();
+        for hour in pattern.hours.get_set_values(ALL_BIT) {
+            for &minute in &minutes {
+                for &second in &seconds {
+                    times.push(i64::from(hour) * 3600 + i64::from(minute) * 60 + i64::from(second));
+                }
+            }
+        }
+        let (first, last) = (*times.first()?, *times.last()?);
+        let same_day_gap = times.windows(2).map(|pair| pair[1] - pair[0]).min();
+
+        // Find the fewest days between two matching days. When the weekday plays no part, the
+        // matching days only vary with leap years, so listing them for 2000-2008 sees every gap,
+        // including the four years from one leap day to the next
+        let start = NaiveDate::from_ymd_opt(2000, 1, 1)?;
+        let fewest_days = if pattern.weekday_independent_days(2000, 1).is_some() {
+            fewest_days_apart((2000..=2008).flat_map(|year| {
+                pattern
+                    .months
+                    .iter_matching(ALL_BIT, Direction::Ascending)
+                    .map(u32::from)
+                    .flat_map(move |month| {
+                        let days = pattern.weekday_independent_days(year, month);
+                        days.unwrap_or_default()
+                            .into_iter()
+                            .filter_map(move |day| NaiveDate::from_ymd_opt(year, month, day))
+                    })
+            }))
+        } else {
+            // The calendar repeats every 400 years, so scanning a little more than that sees
+            // every gap, while schedules with a period repeat within a week
+            let days_to_scan: i64 = if self.period().is_some() {
+                15
+            } else {
+                146_097 + 366
+            };
+            fewest_days_apart(
+                (0..days_to_scan)
+                    .filter_map(|day| start.checked_add_signed(Duration::days(day)))
+                    .filter(|date| {
+                        pattern.month_match(date.month()).unwrap_or(false)
+                            && pattern
+                                .day_match(date.year(), date.month(), date.day())
+                                .unwrap_or(false)
+                    }),
+            )
+        }?;
+
+        // The last time of one matching day is followed by the first time of the next
+        let next_day_gap = fewest_days * SECONDS_PER_DAY - last + first;
+        same_day_gap
+            .into_iter()
+            .chain(Some(next_day_gap))
+            .min()
+            .map(Duration::seconds)
+    }
+
+    /// Returns the time of day the pattern fires at, when it fires at a single time each matching
+    /// day, like `0 30 9 * * *`. Returns `None` for patterns with several times a day.
+    ///
+    /// Useful for showing "daily at 09:30" differently from interval schedules.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::NaiveTime;
+    /// use croner::Cron;
+    ///
+    /// let cron = Cron::new("30 9 * * MON-FRI").parse().expect("Couldn't parse cron string");
+    /// assert_eq!(cron.fixed_time_of_day(), NaiveTime::from_hms_opt(9, 30, 0));
+    ///
+    /// let cron = Cron::new("*/5 * * * *").parse().expect("Couldn't parse cron string");
+    /// assert_eq!(cron.fixed_time_of_day(), None);
+    /// ```
+    pub fn fixed_time_of_day(&self) -> Option<NaiveTime> {
+        let single = |component: &component::CronComponent| {
+            component
+                .is_single_fixed()
+                .then(|| u32::from(component.get_set_values(ALL_BIT)[0]))
+        };
+        NaiveTime::from_hms_opt(
+            single(&self.pattern.hours)?,
+            single(&self.pattern.minutes)?,
+            single(&self.pattern.seconds)?,
+        )
+    }
+
+    /// Estimates how many times the pattern fires on a day it matches.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use croner::Cron;
+    ///
+    /// let cron = Cron::new("*/5 9-17 * * *").parse().expect("Couldn't parse cron string");
+    /// assert_eq!(cron.occurrences_per_day_estimate().unwrap(), 108);
+    /// ```
+    pub fn occurrences_per_day_estimate(&self) -> Result<u64, CronError> {
+        self.pattern.occurrences_per_day_estimate()
+    }
+
+    /// Reports list entries that overlap with, or are already covered by, another entry of the
+    /// same field.
+    ///
+    /// This is purely informational and useful for cleaning up machine generated patterns,
+    /// the pattern matches the same times either way. Weekday and month names are reported
+    /// by their numerical value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use croner::Cron;
+    ///
+    /// let cron = Cron::new("1-5,3-7 * * * *").parse().expect("Couldn't parse cron string");
+    /// assert_eq!(cron.redundancies(), ["minute: 3-7 overlaps 1-5"]);
+    /// ```
+    pub fn redundancies(&self) -> Vec<String> {
+        self.pattern.redundancies()
+    }
+
+    // Internal functions to check for the next matching month/day/hour/minute/second and return the updated time.
+    fn find_next_matching_month(
+        &self,
+        current_time: &mut NaiveDateTime,
+    ) -> Result<bool, CronError> {
+        let mut incremented = false;
+        // Jump straight to the next matching month, moving on to the next year when none is left
+        loop {
+            match self.pattern.next_month_match(current_time.month())? {
+                Some(next_match) if next_match == current_time.month() => break,
+                Some(next_match) => {
+                    set_time(
+                        current_time,
+                        current_time.year(),
+                        next_match,
+                        1,
+                        0,
+                        0,
+                        0,
+                        TimeComponent::Month,
+                    )?;
+                    incremented = true;
+                    break;
+                }
+                None => {
+                    increment_time_component(current_time, TimeComponent::Year)?;
+                    incremented = true;
+                }
+            }
+        }
+        Ok(incremented)
+    }
+
+    fn find_next_matching_day(&self, current_time: &mut NaiveDateTime) -> Result<bool, CronError> {
+        let mut incremented = false;
+        let month = current_time.month();
+        while !self.pattern.day_match(
+            current_time.year(),
+            current_time.month(),
+            current_time.day(),
         )? {
             increment_time_component(current_time, TimeComponent::Day)?;
             incremented = true;
+            // Leaving the month hands over to the month search, which skips non-matching months
+            if current_time.month() != month {
+                break;
+            }
+        }
+
+        Ok(incremented)
+    }
+
+    fn find_next_matching_hour(&self, current_time: &mut NaiveDateTime) -> Result<bool, CronError> {
+        let mut incremented = false;
+        let next_hour_result = self.pattern.next_hour_match(current_time.hour());
+
+        match next_hour_result {
+            Ok(Some(next_match)) if next_match != current_time.hour() => {
+                set_time_component(current_time, TimeComponent::Hour, next_match)?;
+            }
+            Ok(None) => {
+                increment_time_component(current_time, TimeComponent::Day)?;
+                incremented = true;
+            }
+            Err(e) => return Err(e), // Propagate any CronError
+            _ => {}                  // No action needed if the current hour already matches
+        }
+        Ok(incremented)
+    }
+
+    fn find_next_matching_minute(
+        &self,
+        current_time: &mut NaiveDateTime,
+    ) -> Result<bool, CronError> {
+        let mut incremented = false;
+        let next_minute_result = self.pattern.next_minute_match(current_time.minute());
+
+        match next_minute_result {
+            Ok(Some(next_match)) if next_match != current_time.minute() => {
+                incremented = true;
+                set_time_component(current_time, TimeComponent::Minute, next_match)?;
+            }
+            Ok(None) => {
+                incremented = true;
+                increment_time_component(current_time, TimeComponent::Hour)?;
+            }
+            Err(e) => return Err(e), // Propagate the CronError
+            _ => {}                  // No action needed if the current minute matches
+        }
+        Ok(incremented)
+    }
+
+    fn find_next_matching_second(
+        &self,
+        current_time: &mut NaiveDateTime,
+    ) -> Result<bool, CronError> {
+        let mut incremented = false;
+        let next_second_result = self.pattern.next_second_match(current_time.second());
+
+        match next_second_result {
+            Ok(Some(next_match)) => {
+                // If a matching second is found, set it and mark as incremented.
+                set_time_component(current_time, TimeComponent::Second, next_match)?;
+            }
+            Ok(None) => {
+                // If no match is found in the current minute, increment the minute.
+                increment_time_component(current_time, TimeComponent::Minute)?;
+                incremented = true;
+            }
+            Err(e) => {
+                // Propagate any errors encountered during the match process.
+                return Err(e);
+            }
+        }
+        Ok(incremented)
+    }
+
+    // Internal functions to check for the previous matching month/day/hour/minute/second and return the updated time.
+    // Moving back past a component sets the time to the last second of the previous month/day/hour/minute.
+    fn find_previous_matching_month(
+        &self,
+        current_time: &mut NaiveDateTime,
+    ) -> Result<bool, CronError> {
+        let mut decremented = false;
+        while !self.pattern.month_match(current_time.month())? {
+            decrement_time_component(current_time, TimeComponent::Month)?;
+            decremented = true;
+        }
+        Ok(decremented)
+    }
+
+    fn find_previous_matching_day(
+        &self,
+        current_time: &mut NaiveDateTime,
+    ) -> Result<bool, CronError> {
+        let mut decremented = false;
+        while !self.pattern.day_match(
+            current_time.year(),
+            current_time.month(),
+            current_time.day(),
+        )? {
+            decrement_time_component(current_time, TimeComponent::Day)?;
+            decremented = true;
+        }
+        Ok(decremented)
+    }
+
+    fn find_previous_matching_hour(
+        &self,
+        current_time: &mut NaiveDateTime,
+    ) -> Result<bool, CronError> {
+        match self.pattern.previous_hour_match(current_time.hour())? {
+            Some(previous_match) if previous_match != current_time.hour() => {
+                *current_time = at_time(current_time.date(), previous_match, 59, 59)?;
+                Ok(false)
+            }
+            Some(_) => Ok(false), // No action needed if the current hour already matches
+            None => {
+                decrement_time_component(current_time, TimeComponent::Day)?;
+                Ok(true)
+            }
+        }
+    }
+
+    fn find_previous_matching_minute(
+        &self,
+        current_time: &mut NaiveDateTime,
+    ) -> Result<bool, CronError> {
+        match self.pattern.previous_minute_match(current_time.minute())? {
+            Some(previous_match) if previous_match != current_time.minute() => {
+                *current_time =
+                    at_time(current_time.date(), current_time.hour(), previous_match, 59)?;
+                Ok(true)
+            }
+            Some(_) => Ok(false), // No action needed if the current minute already matches
+            None => {
+                decrement_time_component(current_time, TimeComponent::Hour)?;
+                Ok(true)
+            }
+        }
+    }
+
+    fn find_previous_matching_second(
+        &self,
+        current_time: &mut NaiveDateTime,
+    ) -> Result<bool, CronError> {
+        match self.pattern.previous_second_match(current_time.second())? {
+            Some(previous_match) => {
+                *current_time = at_time(
+                    current_time.date(),
+                    current_time.hour(),
+                    current_time.minute(),
+                    previous_match,
+                )?;
+                Ok(false)
+            }
+            None => {
+                decrement_time_component(current_time, TimeComponent::Minute)?;
+                Ok(true)
+            }
+        }
+    }
+
+    pub fn with_dom_and_dow(&mut self) -> &mut Self {
+        self.pattern.with_dom_and_dow();
+        self
+    }
+
+    pub fn with_seconds_optional(&mut self) -> &mut Self {
+        self.pattern.with_seconds_optional();
+        self
+    }
+
+    pub fn with_seconds_required(&mut self) -> &mut Self {
+        self.pattern.with_seconds_required();
+        self
+    }
+
+    pub fn with_alternative_weekdays(&mut self) -> &mut Self {
+        self.pattern.with_alternative_weekdays();
+        self
+    }
+
+    /// Sets the seed used to resolve Jenkins-style hashed values, `H` or `H(a-b)`, into a
+    /// stable pseudo-random value within the field. Deriving the seed from a job's identity
+    /// spreads jobs written as `H H * * *` over the day, while each job keeps its own time.
+    /// The seed defaults to 0.
+    ///
+    /// `H` is allowed in the seconds, minutes, hours and day-of-month fields. Every `H` in a field
+    /// resolves to the same position within its range, so `H,H` is a single value, while
+    /// `H(0-29),H(30-59)` fires twice, 30 minutes apart.
+    ///
+    /// Parsing replaces the hashed values in the pattern with the values they resolved to, so
+    /// `as_str`, `Display` and serialization give a pattern firing at the same times without the
+    /// seed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use croner::Cron;
+    ///
+    /// let first = Cron::new("H H * * *").with_hash_seed(42).parse().expect("Couldn't parse cron string");
+    /// let second = Cron::new("H H * * *").with_hash_seed(42).parse().expect("Couldn't parse cron string");
+    /// assert_eq!(first, second);
+    ///
+    /// let reparsed = Cron::new(first.as_str()).parse().expect("Couldn't parse cron string");
+    /// assert_eq!(first, reparsed);
+    /// ```
+    pub fn with_hash_seed(&mut self, seed: u64) -> &mut Self {
+        self.pattern.with_hash_seed(seed);
+        self
+    }
+
+    /// Rejects patterns firing more than `max_fires_per_day` times on a day they match,
+    /// to catch accidental high-frequency schedules. Unlimited by default.
+    pub fn with_max_fires_per_day(&mut self, max_fires_per_day: u64) -> &mut Self {
+        self.pattern.with_max_fires_per_day(max_fires_per_day);
+        self
+    }
+
+    /// Rejects patterns whose days of month never exist in the selected months, like
+    /// `0 0 30 2 *`, instead of parsing them into a schedule that never fires.
+    ///
+    /// February is counted with 29 days, so `0 0 29 2 *` is still accepted. Days given with `W`,
+    /// like `30W`, need to exist as well. Patterns where the day of week can match on its own
+    /// are never rejected. Disabled by default.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use croner::Cron;
+    ///
+    /// assert!(Cron::new("0 0 30 2 *").with_reject_impossible().parse().is_err());
+    /// assert!(Cron::new("0 0 29 2 *").with_reject_impossible().parse().is_ok());
+    /// assert!(Cron::new("0 0 30W 2 *").with_reject_impossible().parse().is_err());
+    /// ```
+    pub fn with_reject_impossible(&mut self) -> &mut Self {
+        self.pattern.with_reject_impossible();
+        self
+    }
+
+    /// Accepts the weekday and month names of `locale`, like the Swedish `MÅN-FRE`, in addition
+    /// to the English names. Only English names are accepted by default.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use croner::{Cron, Locale};
+    ///
+    /// let cron = Cron::new("0 9 * * MÅN-FRE")
+    ///     .with_locale(Locale::Swedish)
+    ///     .parse()
+    ///     .expect("Couldn't parse cron string");
+    /// assert_eq!(cron.as_str(), "0 9 * * 1-5");
+    /// ```
+    pub fn with_locale(&mut self, locale: Locale) -> &mut Self {
+        self.pattern.with_locale(locale);
+        self
+    }
+
+    /// Configures the parser for Quartz expressions, like `0 15 10 ? * MON-FRI`.
+    ///
+    /// Seconds are required, weekdays are numbered from 1 (Sunday) to 7 (Saturday) and a trailing
+    /// year field is accepted when it is `*`. Quartz's `?`, `L`, `L-n`, `W`, `#` and weekday `L`
+    /// are supported, and day of month and day of week combine like in Quartz when one of them
+    /// is `?`. Steps may start from a single value, like `0/5`, running to the end of the field.
+    /// Explicit years and `LW` have no equivalent and fail to parse.
+    pub fn with_quartz(&mut self) -> &mut Self {
+        self.pattern.with_quartz();
+        self
+    }
+
+    /// Clamps out-of-range values into their field instead of rejecting the pattern.
+    ///
+    /// Plain numbers and the bounds of ranges are clamped, so a minute of `61` becomes `59` and
+    /// `0-70` becomes `0-59`. Every adjustment is listed by `warnings` after parsing. This helps
+    /// importing legacy crontabs without editing them by hand.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use croner::Cron;
+    ///
+    /// let cron = Cron::new("0-70 * * * *")
+    ///     .with_lenient()
+    ///     .parse()
+    ///     .expect("Couldn't parse cron string");
+    /// assert_eq!(cron.warnings(), ["minute: 0-70 clamped to 0-59"]);
+    /// ```
+    pub fn with_lenient(&mut self) -> &mut Self {
+        self.pattern.with_lenient();
+        self
+    }
+
+    /// Returns the adjustments made while parsing with `with_lenient`, empty otherwise.
+    pub fn warnings(&self) -> &[String] {
+        self.pattern.warnings()
+    }
+
+    /// Sets how occurrences falling into a daylight saving gap are handled,
+    /// defaults to `DstGapPolicy::SnapForward`.
+    pub fn with_dst_gap(&mut self, policy: DstGapPolicy) -> &mut Self {
+        self.pattern.with_dst_gap(policy);
+        self
+    }
+
+    /// Sets how occurrences falling into a repeated hour of a daylight saving overlap
+    /// are handled, defaults to `DstOverlapPolicy::FireFirst`.
+    pub fn with_dst_overlap(&mut self, policy: DstOverlapPolicy) -> &mut Self {
+        self.pattern.with_dst_overlap(policy);
+        self
+    }
+
+    pub fn as_str(&self) -> &str {
+        self.pattern.as_str()
+    }
+
+    /// Returns the parsed pattern.
+    ///
+    /// The pattern is read-only, as changing the parsed fields directly could leave them in an
+    /// inconsistent state. Code reading the formerly public `pattern` field, like
+    /// `cron.pattern.to_string()`, reads it through this accessor instead, like
+    /// `cron.pattern().to_string()`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use croner::Cron;
+    ///
+    /// let cron = Cron::new("0 12 * * MON").parse().expect("Couldn't parse cron string");
+    /// assert_eq!(cron.pattern().as_str(), "0 12 * * 1");
+    /// assert!(!cron.pattern().dom_and_dow());
+    /// ```
+    pub fn pattern(&self) -> &CronPattern {
+        &self.pattern
+    }
+
+    /// Returns a normalized six-field pattern rendered from the parsed fields.
+    ///
+    /// Unlike `as_str`, which keeps the pattern as written, different spellings of the same fields
+    /// render identically: values are numeric and sorted, consecutive values are collapsed into
+    /// ranges, and weekday 7 becomes 0. A week of days combined with weekdays using
+    /// `with_dom_and_dow`, like `1-7` and `MON`, renders as the nth weekday it describes, `1#1`.
+    /// Two crons compare equal exactly when their canonical patterns and parser options do.
+    ///
+    /// Fields are normalized one by one, so schedules that fire at the same times can still
+    /// render differently, like `0 0 1-31 * *` and `0 0 * * *`. Parser options like
+    /// `with_dom_and_dow` are not part of the pattern string.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use croner::Cron;
+    ///
+    /// let daily = Cron::new("@daily").parse().expect("Couldn't parse cron string");
+    /// let midnight = Cron::new("0 0 * * *").parse().expect("Couldn't parse cron string");
+    /// assert_eq!(daily.canonical(), "0 0 0 * * *");
+    /// assert_eq!(daily.canonical(), midnight.canonical());
+    /// ```
+    pub fn canonical(&self) -> String {
+        self.pattern.canonical()
+    }
+
+    /// Returns the normalized form of each field, from seconds to year, like `canonical` but
+    /// with one string per field so they can be shown or edited separately.
+    ///
+    /// Omitted seconds are rendered as `"0"`. Years can not be restricted, so the year field is
+    /// always `"*"`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use croner::Cron;
+    ///
+    /// let cron = Cron::new("*/15 9-17 * * MON-FRI").parse().expect("Couldn't parse cron string");
+    /// assert_eq!(cron.as_fields(), ["0", "*/15", "9-17", "*", "*", "1-5", "*"]);
+    /// ```
+    pub fn as_fields(&self) -> [String; 7] {
+        let [seconds, minutes, hours, days, months, days_of_week] = self.pattern.canonical_fields();
+        [
+            seconds,
+            minutes,
+            hours,
+            days,
+            months,
+            days_of_week,
+            String::from("*"),
+        ]
+    }
+
+    /// Returns the values each field of the parsed pattern resolved to, for tools that inspect
+    /// or visualize schedules without depending on how fields are stored internally.
+    ///
+    /// This is the same as `CronStructured::from(&cron)`, see [`structured::CronStructured`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use croner::Cron;
+    ///
+    /// let cron = Cron::new("0 9 15W,L * FRI#2").parse().expect("Couldn't parse cron string");
+    /// let fields = cron.fields();
+    /// assert_eq!(fields.hours, vec![9]);
+    /// assert_eq!(fields.closest_weekdays, vec![15]);
+    /// assert!(fields.last_day_of_month);
+    /// assert_eq!(fields.nth_days_of_week, vec![(5, 2)]);
+    /// ```
+    pub fn fields(&self) -> structured::CronStructured {
+        structured::CronStructured::from(self)
+    }
+}
+
+impl core::fmt::Display for Cron {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.pattern)
+    }
+}
+
+// Two crons are equal when their canonical patterns and parser options are equal, so "@daily"
+// equals "0 0 * * *", and "0 0 1-7 * MON" combined with with_dom_and_dow equals "0 0 * * MON#1".
+// Schedules only equivalent across fields, like "0 0 1-31 * *" and "0 0 * * *", are not equal.
+// Unparsed crons compare by their pattern text.
+impl PartialEq for Cron {
+    fn eq(&self, other: &Self) -> bool {
+        self.pattern.schedule_key() == other.pattern.schedule_key()
+    }
+}
+
+impl Eq for Cron {}
+
+impl Hash for Cron {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.pattern.schedule_key().hash(state);
+    }
+}
+
+// Enables creating a Cron instance from a string slice, returning a CronError if parsing fails.
+impl FromStr for Cron {
+    type Err = CronError;
+
+    fn from_str(cron_string: &str) -> Result<Cron, CronError> {
+        let res = Cron::new(cron_string);
+        Ok(res)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for Cron {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(self.pattern.as_str())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for Cron {
+    fn deserialize<D>(deserializer: D) -> Result<Cron, D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        struct CronVisitor;
+
+        impl Visitor<'_> for CronVisitor {
+            type Value = Cron;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("a valid cron pattern")
+            }
+
+            fn visit_str<E>(self, value: &str) -> Result<Cron, E>
+            where
+                E: de::Error,
+            {
+                Cron::new(value).parse().map_err(de::Error::custom)
+            }
+        }
+
+        deserializer.deserialize_str(CronVisitor)
+    }
+}
+
+// Recursive function to handle setting the time and managing overflows.
+#[allow(clippy::too_many_arguments)]
+fn set_time(
+    current_time: &mut NaiveDateTime,
+    year: i32,
+    month: u32,
+    day: u32,
+    hour: u32,
+    minute: u32,
+    second: u32,
+    component: TimeComponent,
+) -> Result<(), CronError> {
+    // First, try creating a NaiveDate and NaiveTime
+    match (
+        NaiveDate::from_ymd_opt(year, month, day),
+        NaiveTime::from_hms_opt(hour, minute, second),
+    ) {
+        (Some(date), Some(time)) => {
+            // Combine date and time into NaiveDateTime
+            *current_time = date.and_time(time);
+            Ok(())
+        }
+        _ => {
+            // Handle invalid date or overflow by incrementing the next higher component.
+            match component {
+                TimeComponent::Second => set_time(
+                    current_time,
+                    year,
+                    month,
+                    day,
+                    hour,
+                    minute + 1,
+                    0,
+                    TimeComponent::Minute,
+                ),
+                TimeComponent::Minute => set_time(
+                    current_time,
+                    year,
+                    month,
+                    day,
+                    hour + 1,
+                    0,
+                    0,
+                    TimeComponent::Hour,
+                ),
+                TimeComponent::Hour => set_time(
+                    current_time,
+                    year,
+                    month,
+                    day + 1,
+                    0,
+                    0,
+                    0,
+                    TimeComponent::Day,
+                ),
+                TimeComponent::Day => set_time(
+                    current_time,
+                    year,
+                    month + 1,
+                    1,
+                    0,
+                    0,
+                    0,
+                    TimeComponent::Month,
+                ),
+                TimeComponent::Month => {
+                    set_time(current_time, year + 1, 1, 1, 0, 0, 0, TimeComponent::Year)
+                }
+                TimeComponent::Year => Err(CronError::InvalidDate),
+            }
+        }
+    }
+}
+
+fn set_time_component(
+    current_time: &mut NaiveDateTime,
+    component: TimeComponent,
+    set_to: u32,
+) -> Result<(), CronError> {
+    // Extract all parts
+    let (year, month, day, hour, minute, _second) = (
+        current_time.year(),
+        current_time.month(),
+        current_time.day(),
+        current_time.hour(),
+        current_time.minute(),
+        current_time.second(),
+    );
+
+    match component {
+        TimeComponent::Year => set_time(current_time, set_to as i32, 0, 0, 0, 0, 0, component),
+        TimeComponent::Month => set_time(current_time, year, set_to, 0, 0, 0, 0, component),
+        TimeComponent::Day => set_time(current_time, year, month, set_to, 0, 0, 0, component),
+        TimeComponent::Hour => set_time(current_time, year, month, day, set_to, 0, 0, component),
+        TimeComponent::Minute => {
+            set_time(current_time, year, month, day, hour, set_to, 0, component)
+        }
+        TimeComponent::Second => set_time(
+            current_time,
+            year,
+            month,
+            day,
+            hour,
+            minute,
+            set_to,
+            component,
+        ),
+    }
+}
+
+// Convert `NaiveDateTime` back to `DateTime<Tz>`
+pub fn from_naive<Tz: TimeZone>(
+    naive_time: NaiveDateTime,
+    timezone: &Tz,
+) -> Result<DateTime<Tz>, CronError> {
+    match timezone.from_local_datetime(&naive_time) {
+        chrono::LocalResult::Single(dt) => Ok(dt),
+        _ => Err(CronError::InvalidTime),
+    }
+}
+
+fn increment_time_component(
+    current_time: &mut NaiveDateTime,
+    component: TimeComponent,
+) -> Result<(), CronError> {
+    // Check for time overflow
+    if current_time.year() >= YEAR_UPPER_LIMIT {
+        return Err(CronError::TimeSearchLimitExceeded);
+    }
+
+    // Extract all parts
+    let (year, month, day, hour, minute, second) = (
+        current_time.year(),
+        current_time.month(),
+        current_time.day(),
+        current_time.hour(),
+        current_time.minute(),
+        current_time.second(),
+    );
+
+    // Increment the component and try to set the new time.
+    match component {
+        TimeComponent::Year => set_time(current_time, year + 1, 1, 1, 0, 0, 0, component),
+        TimeComponent::Month => set_time(current_time, year, month + 1, 1, 0, 0, 0, component),
+        TimeComponent::Day => set_time(current_time, year, month, day + 1, 0, 0, 0, component),
+        TimeComponent::Hour => set_time(current_time, year, month, day, hour + 1, 0, 0, component),
+        TimeComponent::Minute => set_time(
+            current_time,
+            year,
+            month,
+            day,
+            hour,
+            minute + 1,
+            0,
+            component,
+        ),
+        TimeComponent::Second => set_time(
+            current_time,
+            year,
+            month,
+            day,
+            hour,
+            minute,
+            second + 1,
+            component,
+        ),
+    }
+}
+
+// Returns the first of the repeated wall-clock times containing naive_time, which span overlap
+fn first_repeated_time<Tz: TimeZone>(
+    naive_time: NaiveDateTime,
+    overlap: Duration,
+    timezone: &Tz,
+) -> NaiveDateTime {
+    let is_repeated = |time: &NaiveDateTime| {
+        matches!(
+            timezone.from_local_datetime(time),
+            LocalResult::Ambiguous(..)
+        )
+    };
+    let (mut before, mut first) = (naive_time - overlap, naive_time);
+    while first.signed_duration_since(before) > Duration::seconds(1) {
+        let middle =
+            before + Duration::seconds(first.signed_duration_since(before).num_seconds() / 2);
+        if is_repeated(&middle) {
+            first = middle;
+        } else {
+            before = middle;
         }
+    }
+    // Transitions happen on whole seconds, so a fraction carried over from naive_time is dropped
+    first.with_nanosecond(0).unwrap_or(first)
+}
 
-        Ok(incremented)
+// Returns the last of the repeated wall-clock times containing naive_time, which span overlap
+fn last_repeated_time<Tz: TimeZone>(
+    naive_time: NaiveDateTime,
+    overlap: Duration,
+    timezone: &Tz,
+) -> NaiveDateTime {
+    let is_repeated = |time: &NaiveDateTime| {
+        matches!(
+            timezone.from_local_datetime(time),
+            LocalResult::Ambiguous(..)
+        )
+    };
+    let (mut last, mut after) = (naive_time, naive_time + overlap);
+    while after.signed_duration_since(last) > Duration::seconds(1) {
+        let middle = last + Duration::seconds(after.signed_duration_since(last).num_seconds() / 2);
+        if is_repeated(&middle) {
+            last = middle;
+        } else {
+            after = middle;
+        }
     }
+    last
+}
 
-    fn find_next_matching_hour(&self, current_time: &mut NaiveDateTime) -> Result<bool, CronError> {
-        let mut incremented = false;
-        let next_hour_result = self.pattern.next_hour_match(current_time.hour());
+// Returns the first instant whose wall-clock time is at or after naive_time. Daylight saving
+// transitions happen on whole minutes, so a skipped time resolves to the first minute after the gap
+fn first_instant_at_or_after<Tz: TimeZone>(
+    naive_time: NaiveDateTime,
+    timezone: &Tz,
+) -> Result<DateTime<Tz>, CronError> {
+    if let Some(tz_datetime) = timezone.from_local_datetime(&naive_time).earliest() {
+        return Ok(tz_datetime);
+    }
+    let mut minute = naive_time
+        .with_second(0)
+        .and_then(|time| time.with_nanosecond(0))
+        .ok_or(CronError::InvalidTime)?;
+    loop {
+        minute = minute
+            .checked_add_signed(Duration::minutes(1))
+            .ok_or(CronError::InvalidTime)?;
+        if let Some(tz_datetime) = timezone.from_local_datetime(&minute).earliest() {
+            return Ok(tz_datetime);
+        }
+        if minute.signed_duration_since(naive_time) > Duration::days(2) {
+            return Err(CronError::InvalidTime);
+        }
+    }
+}
 
-        match next_hour_result {
-            Ok(Some(next_match)) if next_match != current_time.hour() => {
-                set_time_component(current_time, TimeComponent::Hour, next_match)?;
-            }
-            Ok(None) => {
-                increment_time_component(current_time, TimeComponent::Day)?;
-                incremented = true;
-            }
-            Err(e) => return Err(e), // Propagate any CronError
-            _ => {}                  // No action needed if the current hour already matches
+// Returns the fewest days between two consecutive dates, or None with fewer than two dates.
+fn fewest_days_apart(dates: impl Iterator<Item = NaiveDate>) -> Option<i64> {
+    let mut previous: Option<NaiveDate> = None;
+    let mut fewest = None;
+    for date in dates {
+        if let Some(previous) = previous {
+            let days = (date - previous).num_days();
+            fewest = fewest.into_iter().chain(Some(days)).min();
         }
-        Ok(incremented)
+        previous = Some(date);
     }
+    fewest
+}
 
-    fn find_next_matching_minute(
-        &self,
-        current_time: &mut NaiveDateTime,
-    ) -> Result<bool, CronError> {
-        let mut incremented = false;
-        let next_minute_result = self.pattern.next_minute_match(current_time.minute());
+// Counts the values of a range for which is_match returns true.
+fn count_matching(
+    range: RangeInclusive<u32>,
+    is_match: impl Fn(u32) -> Result<bool, CronError>,
+) -> Result<u64, CronError> {
+    range
+        .into_iter()
+        .try_fold(0, |count, value| Ok(count + u64::from(is_match(value)?)))
+}
 
-        match next_minute_result {
-            Ok(Some(next_match)) if next_match != current_time.minute() => {
-                incremented = true;
-                set_time_component(current_time, TimeComponent::Minute, next_match)?;
-            }
-            Ok(None) => {
-                incremented = true;
-                increment_time_component(current_time, TimeComponent::Hour)?;
-            }
-            Err(e) => return Err(e), // Propagate the CronError
-            _ => {}                  // No action needed if the current minute matches
-        }
-        Ok(incremented)
+// Checks whether a date has the same UTC offset throughout, i.e. no daylight saving transition.
+fn has_constant_offset<Tz: TimeZone>(timezone: &Tz, date: NaiveDate) -> bool {
+    let offset_at = |hour, minute, second| match at_time(date, hour, minute, second)
+        .map(|time| timezone.offset_from_local_datetime(&time))
+    {
+        Ok(chrono::LocalResult::Single(offset)) => Some(offset.fix()),
+        _ => None,
+    };
+    match (offset_at(0, 0, 0), offset_at(23, 59, 59)) {
+        (Some(first), Some(last)) => first == last,
+        _ => false,
     }
+}
 
-    fn find_next_matching_second(
-        &self,
-        current_time: &mut NaiveDateTime,
-    ) -> Result<bool, CronError> {
-        let mut incremented = false;
-        let next_second_result = self.pattern.next_second_match(current_time.second());
+// Moves the time to the last second before the start of the current month/day/hour/minute.
+fn decrement_time_component(
+    current_time: &mut NaiveDateTime,
+    component: TimeComponent,
+) -> Result<(), CronError> {
+    // Check for time underflow
+    if current_time.year() <= YEAR_LOWER_LIMIT {
+        return Err(CronError::TimeSearchLimitExceeded);
+    }
 
-        match next_second_result {
-            Ok(Some(next_match)) => {
-                // If a matching second is found, set it and mark as incremented.
-                set_time_component(current_time, TimeComponent::Second, next_match)?;
-            }
-            Ok(None) => {
-                // If no match is found in the current minute, increment the minute.
-                increment_time_component(current_time, TimeComponent::Minute)?;
-                incremented = true;
-            }
-            Err(e) => {
-                // Propagate any errors encountered during the match process.
-                return Err(e);
-            }
+    let date = current_time.date();
+    let start = match component {
+        TimeComponent::Month => date.with_day(1).ok_or(CronError::InvalidDate)?,
+        _ => date,
+    }
+    .and_hms_opt(
+        match component {
+            TimeComponent::Hour | TimeComponent::Minute => current_time.hour(),
+            _ => 0,
+        },
+        match component {
+            TimeComponent::Minute => current_time.minute(),
+            _ => 0,
+        },
+        0,
+    )
+    .ok_or(CronError::InvalidTime)?;
+
+    *current_time = start
+        .checked_sub_signed(Duration::seconds(1))
+        .ok_or(CronError::TimeSearchLimitExceeded)?;
+    Ok(())
+}
+
+// Combines a date with the given hour, minute and second.
+fn at_time(
+    date: NaiveDate,
+    hour: u32,
+    minute: u32,
+    second: u32,
+) -> Result<NaiveDateTime, CronError> {
+    date.and_hms_opt(hour, minute, second)
+        .ok_or(CronError::InvalidTime)
+}
+
+// The tests use the local time zone and the system clock, which chrono only provides with std
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use chrono::{Local, TimeZone, Utc};
+    #[cfg(feature = "serde")]
+    use serde_test::{assert_de_tokens_error, assert_tokens, Token};
+    #[test]
+    fn test_is_time_matching() -> Result<(), CronError> {
+        // This pattern is meant to match first second of 9 am on the first day of January.
+        let cron = Cron::new("0 9 1 1 *").parse()?;
+        let time_matching = Local.with_ymd_and_hms(2023, 1, 1, 9, 0, 0).unwrap();
+        let time_not_matching = Local.with_ymd_and_hms(2023, 1, 1, 10, 0, 0).unwrap();
+
+        assert!(cron.is_time_matching(&time_matching)?);
+        assert!(!cron.is_time_matching(&time_not_matching)?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_leap_second() -> Result<(), CronError> {
+        // 2016-12-31 ended with the leap second 23:59:60
+        let leap_second = NaiveDate::from_ymd_opt(2016, 12, 31)
+            .unwrap()
+            .and_hms_milli_opt(23, 59, 59, 1_500)
+            .unwrap();
+        let leap_second = Utc.from_utc_datetime(&leap_second);
+        assert_eq!(leap_second.to_string(), "2016-12-31 23:59:60.500 UTC");
+
+        // Matches like second 59
+        let last_second = Cron::new("59 59 23 * * *")
+            .with_seconds_required()
+            .parse()?;
+        assert!(last_second.is_time_matching(&leap_second)?);
+        let midnight = Cron::new("0 0 0 * * *").with_seconds_required().parse()?;
+        assert!(!midnight.is_time_matching(&leap_second)?);
+
+        // Searches continue from around it
+        let every_second = Cron::new("* * * * * *").with_seconds_required().parse()?;
+        assert_eq!(
+            every_second.find_next_occurrence(&leap_second, true)?,
+            Utc.with_ymd_and_hms(2017, 1, 1, 0, 0, 0).unwrap()
+        );
+        assert_eq!(
+            every_second.find_previous_occurrence(&leap_second, false)?,
+            Utc.with_ymd_and_hms(2016, 12, 31, 23, 59, 59).unwrap()
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_is_time_matching_in() -> Result<(), CronError> {
+        use chrono_tz::{America::New_York, Asia::Tokyo};
+
+        let cron = Cron::new("0 12 * * *").parse()?;
+
+        // Noon in New York (UTC-5) and in Tokyo (UTC+9) on 2024-01-15
+        let new_york_noon = Utc.with_ymd_and_hms(2024, 1, 15, 17, 0, 0).unwrap();
+        let tokyo_noon = Utc.with_ymd_and_hms(2024, 1, 15, 3, 0, 0).unwrap();
+
+        assert!(cron.is_time_matching_in(&new_york_noon, &New_York)?);
+        assert!(!cron.is_time_matching_in(&new_york_noon, &Tokyo)?);
+        assert!(cron.is_time_matching_in(&tokyo_noon, &Tokyo)?);
+        assert!(!cron.is_time_matching_in(&tokyo_noon, &New_York)?);
+        assert!(!cron.is_time_matching(&new_york_noon)?);
+
+        // The date is taken from the target timezone as well, 08:00 on Monday in Tokyo is still
+        // Sunday in UTC
+        let mondays = Cron::new("0 * * * MON").parse()?;
+        let monday_in_tokyo = Utc.with_ymd_and_hms(2024, 1, 14, 23, 0, 0).unwrap();
+        assert!(mondays.is_time_matching_in(&monday_in_tokyo, &Tokyo)?);
+        assert!(!mondays.is_time_matching_in(&monday_in_tokyo, &Utc)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_is_time_matching_ignoring_seconds() -> Result<(), CronError> {
+        let cron = Cron::new("0 30 9 * * *").with_seconds_required().parse()?;
+        for second in 0..60 {
+            let time = Local.with_ymd_and_hms(2023, 1, 1, 9, 30, second).unwrap();
+            assert!(cron.is_time_matching_ignoring_seconds(&time)?);
+            assert_eq!(cron.is_time_matching(&time)?, second == 0);
         }
-        Ok(incremented)
+
+        for (hour, minute) in [(9, 29), (9, 31), (10, 30)] {
+            let time = Local.with_ymd_and_hms(2023, 1, 1, hour, minute, 0).unwrap();
+            assert!(!cron.is_time_matching_ignoring_seconds(&time)?);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_last_day_of_february_non_leap_year() -> Result<(), CronError> {
+        // This pattern is meant to match every second of 9 am on the last day of February in a non-leap year.
+        let cron = Cron::new("0 9 L 2 *").parse()?;
+
+        // February 28th, 2023 is the last day of February in a non-leap year.
+        let time_matching = Local.with_ymd_and_hms(2023, 2, 28, 9, 0, 0).unwrap();
+        let time_not_matching = Local.with_ymd_and_hms(2023, 2, 28, 10, 0, 0).unwrap();
+        let time_not_matching_2 = Local.with_ymd_and_hms(2023, 2, 27, 9, 0, 0).unwrap();
+
+        assert!(cron.is_time_matching(&time_matching)?);
+        assert!(!cron.is_time_matching(&time_not_matching)?);
+        assert!(!cron.is_time_matching(&time_not_matching_2)?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_last_day_of_february_leap_year() -> Result<(), CronError> {
+        // This pattern is meant to match every second of 9 am on the last day of February in a leap year.
+        let cron = Cron::new("0 9 L 2 *").parse()?;
+
+        // February 29th, 2024 is the last day of February in a leap year.
+        let time_matching = Local.with_ymd_and_hms(2024, 2, 29, 9, 0, 0).unwrap();
+        let time_not_matching = Local.with_ymd_and_hms(2024, 2, 29, 10, 0, 0).unwrap();
+        let time_not_matching_2 = Local.with_ymd_and_hms(2024, 2, 28, 9, 0, 0).unwrap();
+
+        assert!(cron.is_time_matching(&time_matching)?);
+        assert!(!cron.is_time_matching(&time_not_matching)?);
+        assert!(!cron.is_time_matching(&time_not_matching_2)?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_last_day_of_month_offset() -> Result<(), CronError> {
+        // This pattern is meant to match midnight on the third-to-last day of each month.
+        let cron = Cron::new("0 0 L-3 * *").parse()?;
+
+        assert!(cron.is_time_matching(&Local.with_ymd_and_hms(2023, 2, 25, 0, 0, 0).unwrap())?);
+        assert!(cron.is_time_matching(&Local.with_ymd_and_hms(2024, 2, 26, 0, 0, 0).unwrap())?);
+        assert!(cron.is_time_matching(&Local.with_ymd_and_hms(2024, 1, 28, 0, 0, 0).unwrap())?);
+        assert!(!cron.is_time_matching(&Local.with_ymd_and_hms(2024, 2, 25, 0, 0, 0).unwrap())?);
+        assert!(!cron.is_time_matching(&Local.with_ymd_and_hms(2024, 2, 29, 0, 0, 0).unwrap())?);
+
+        let start_time = Local.with_ymd_and_hms(2023, 2, 1, 0, 0, 0).unwrap();
+        let runs: Vec<_> = cron.iter_from(start_time).take(3).collect();
+        assert_eq!(
+            runs[0],
+            Local.with_ymd_and_hms(2023, 2, 25, 0, 0, 0).unwrap()
+        );
+        assert_eq!(
+            runs[1],
+            Local.with_ymd_and_hms(2023, 3, 28, 0, 0, 0).unwrap()
+        );
+        assert_eq!(
+            runs[2],
+            Local.with_ymd_and_hms(2023, 4, 27, 0, 0, 0).unwrap()
+        );
+
+        // Can be combined with L and plain days
+        let cron = Cron::new("0 0 1,L-1,L * *").parse()?;
+        let runs: Vec<_> = cron.iter_from(start_time).take(3).collect();
+        assert_eq!(
+            runs[0],
+            Local.with_ymd_and_hms(2023, 2, 1, 0, 0, 0).unwrap()
+        );
+        assert_eq!(
+            runs[1],
+            Local.with_ymd_and_hms(2023, 2, 27, 0, 0, 0).unwrap()
+        );
+        assert_eq!(
+            runs[2],
+            Local.with_ymd_and_hms(2023, 2, 28, 0, 0, 0).unwrap()
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_last_friday_of_year() -> Result<(), CronError> {
+        // This pattern is meant to match 0:00:00 last friday of current year
+        let cron = Cron::new("0 0 * * FRI#L").parse()?;
+
+        // February 29th, 2024 is the last day of February in a leap year.
+        let time_matching = Local.with_ymd_and_hms(2023, 12, 29, 0, 0, 0).unwrap();
+
+        assert!(cron.is_time_matching(&time_matching)?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_last_friday_of_year_alternative_alpha_syntax() -> Result<(), CronError> {
+        // This pattern is meant to match 0:00:00 last friday of current year
+        let cron = Cron::new("0 0 * * FRIl").parse()?;
+
+        // February 29th, 2024 is the last day of February in a leap year.
+        let time_matching = Local.with_ymd_and_hms(2023, 12, 29, 0, 0, 0).unwrap();
+
+        assert!(cron.is_time_matching(&time_matching)?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_last_friday_of_year_alternative_number_syntax() -> Result<(), CronError> {
+        // This pattern is meant to match 0:00:00 last friday of current year
+        let cron = Cron::new("0 0 * * 5L").parse()?;
+
+        // February 29th, 2024 is the last day of February in a leap year.
+        let time_matching = Local.with_ymd_and_hms(2023, 12, 29, 0, 0, 0).unwrap();
+
+        assert!(cron.is_time_matching(&time_matching)?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_find_next_occurrence() -> Result<(), CronError> {
+        // This pattern is meant to match every minute at 30 seconds past the minute.
+        let cron = Cron::new("* * * * * *").with_seconds_optional().parse()?;
+
+        // Set the start time to a known value.
+        let start_time = Local.with_ymd_and_hms(2023, 1, 1, 0, 0, 29).unwrap();
+        // Calculate the next occurrence from the start time.
+        let next_occurrence = cron.find_next_occurrence(&start_time, false)?;
+
+        // Verify that the next occurrence is at the expected time.
+        let expected_time = Local.with_ymd_and_hms(2023, 1, 1, 0, 0, 30).unwrap();
+        assert_eq!(next_occurrence, expected_time);
+
+        Ok(())
     }
 
-    pub fn with_dom_and_dow(&mut self) -> &mut Self {
-        self.pattern.with_dom_and_dow();
-        self
+    #[test]
+    fn test_find_next_n() -> Result<(), CronError> {
+        let cron = Cron::new("0 0 * * *").parse()?;
+        let start_time = Local.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+
+        let inclusive = cron.find_next_n(&start_time, 3, true)?;
+        let expected: Vec<_> = cron.iter_from(start_time).take(3).collect();
+        assert_eq!(inclusive, expected);
+        assert_eq!(inclusive[0], start_time);
+
+        let exclusive = cron.find_next_n(&start_time, 3, false)?;
+        let expected: Vec<_> = cron.iter_after(start_time).take(3).collect();
+        assert_eq!(exclusive, expected);
+
+        assert!(cron.find_next_n(&start_time, 0, true)?.is_empty());
+
+        // Errors are propagated instead of truncating the result
+        let cron = Cron::new("0 0 30 2 *").parse()?;
+        assert!(matches!(
+            cron.find_next_n(&start_time, 2, false),
+            Err(CronError::TimeSearchLimitExceeded)
+        ));
+
+        Ok(())
     }
 
-    pub fn with_seconds_optional(&mut self) -> &mut Self {
-        self.pattern.with_seconds_optional();
-        self
+    #[test]
+    fn test_iter_between() -> Result<(), CronError> {
+        let cron = Cron::new("0 0 * * *").parse()?;
+        let start_time = Local.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        let end_time = Local.with_ymd_and_hms(2024, 1, 7, 0, 0, 0).unwrap();
+
+        // Both ends are inclusive
+        let times: Vec<_> = cron.iter_between(start_time, end_time).collect();
+        assert_eq!(times.len(), 7);
+        assert_eq!(times.first(), Some(&start_time));
+        assert_eq!(times.last(), Some(&end_time));
+
+        // Empty and reversed ranges yield nothing
+        let noon = Local.with_ymd_and_hms(2024, 1, 1, 12, 0, 0).unwrap();
+        let evening = Local.with_ymd_and_hms(2024, 1, 1, 18, 0, 0).unwrap();
+        assert_eq!(cron.iter_between(noon, evening).count(), 0);
+        assert_eq!(cron.iter_between(end_time, start_time).count(), 0);
+
+        Ok(())
     }
 
-    pub fn with_seconds_required(&mut self) -> &mut Self {
-        self.pattern.with_seconds_required();
-        self
+    #[test]
+    fn test_matches_in() -> Result<(), CronError> {
+        let cron = Cron::new("0 0 * * *").parse()?;
+        let jan1 = Local.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        let jan5 = Local.with_ymd_and_hms(2024, 1, 5, 0, 0, 0).unwrap();
+
+        let matches = cron.matches_in(jan1..=jan5)?;
+        assert_eq!(matches.len(), 5);
+        assert_eq!(matches, cron.iter_between(jan1, jan5).collect::<Vec<_>>());
+        assert!(cron.matches_in(jan5..=jan1)?.is_empty());
+
+        // Running out of occurrences ends the result instead of failing
+        let cron = Cron::new("0 0 30 2 *").parse()?;
+        assert!(cron.matches_in(jan1..=jan5)?.is_empty());
+
+        Ok(())
     }
 
-    pub fn with_alternative_weekdays(&mut self) -> &mut Self {
-        self.pattern.with_alternative_weekdays();
-        self
+    #[test]
+    fn test_occurrences_in() -> Result<(), CronError> {
+        let time = Local.with_ymd_and_hms(2024, 1, 1, 9, 30, 45).unwrap();
+        let cron = Cron::new("*/15 * * * * *")
+            .with_seconds_required()
+            .parse()?;
+        assert_eq!(cron.occurrences_in(&time, TimeUnit::Minute)?.len(), 4);
+        assert_eq!(cron.occurrences_in(&time, TimeUnit::Hour)?.len(), 240);
+
+        let cron = Cron::new("0 */6 * * *").parse()?;
+        let hits = cron.occurrences_in(&time, TimeUnit::Day)?;
+        assert_eq!(
+            hits,
+            [0, 6, 12, 18]
+                .map(|hour| Local.with_ymd_and_hms(2024, 1, 1, hour, 0, 0).unwrap())
+                .to_vec()
+        );
+
+        // No match within the unit
+        assert!(cron.occurrences_in(&time, TimeUnit::Hour)?.is_empty());
+        let cron = Cron::new("0 0 30 2 *").parse()?;
+        assert!(cron.occurrences_in(&time, TimeUnit::Day)?.is_empty());
+        Ok(())
     }
 
-    pub fn as_str(&self) -> &str {
-        self.pattern.as_str()
+    #[test]
+    fn test_occurrences_in_across_dst() -> Result<(), CronError> {
+        use chrono_tz::America::New_York;
+
+        // The repeated hour of 2024-11-03 is covered as a whole
+        let cron = Cron::new("*/30 * * * *")
+            .with_dst_overlap(DstOverlapPolicy::FireBoth)
+            .parse()?;
+        let time = New_York.with_ymd_and_hms(2024, 11, 3, 0, 45, 0).unwrap() + Duration::hours(1);
+        let hits = cron.occurrences_in(&time, TimeUnit::Hour)?;
+        assert_eq!(hits.len(), 4);
+        assert!(hits.iter().all(|hit| hit.hour() == 1));
+
+        // 2024-03-10 only has 23 hours
+        let cron = Cron::new("0 * * * *").parse()?;
+        let time = New_York.with_ymd_and_hms(2024, 3, 10, 12, 0, 0).unwrap();
+        assert_eq!(cron.occurrences_in(&time, TimeUnit::Day)?.len(), 23);
+        let time = New_York.with_ymd_and_hms(2024, 11, 3, 12, 0, 0).unwrap();
+        assert_eq!(cron.occurrences_in(&time, TimeUnit::Day)?.len(), 24);
+        Ok(())
     }
-}
 
-impl std::fmt::Display for Cron {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", self.pattern)
+    #[test]
+    fn test_find_previous_occurrence() -> Result<(), CronError> {
+        let start_time = Local.with_ymd_and_hms(2024, 3, 1, 0, 0, 0).unwrap();
+
+        let cron = Cron::new("0 12 L * *").parse()?;
+        let expected = Local.with_ymd_and_hms(2024, 2, 29, 12, 0, 0).unwrap();
+        assert_eq!(cron.find_previous_occurrence(&start_time, false)?, expected);
+
+        let cron = Cron::new("*/15 * * * * *")
+            .with_seconds_required()
+            .parse()?;
+        assert_eq!(
+            cron.find_previous_occurrence(&start_time, true)?,
+            start_time
+        );
+        let expected = Local.with_ymd_and_hms(2024, 2, 29, 23, 59, 45).unwrap();
+        assert_eq!(cron.find_previous_occurrence(&start_time, false)?, expected);
+
+        let cron = Cron::new("30 9 * 6 MON").parse()?;
+        let expected = Local.with_ymd_and_hms(2023, 6, 26, 9, 30, 0).unwrap();
+        assert_eq!(cron.find_previous_occurrence(&start_time, false)?, expected);
+
+        // The previous occurrence of the next occurrence is the starting point
+        let cron = Cron::new("5 4 * * SUN#2").parse()?;
+        let next = cron.find_next_occurrence(&start_time, false)?;
+        let previous = cron.find_previous_occurrence(&next, false)?;
+        assert_eq!(cron.find_next_occurrence(&previous, false)?, next);
+
+        Ok(())
     }
-}
 
-// Enables creating a Cron instance from a string slice, returning a CronError if parsing fails.
-impl FromStr for Cron {
-    type Err = CronError;
+    #[test]
+    fn test_step_by_occurrences() -> Result<(), CronError> {
+        let cron = Cron::new("0 9 * * *").parse()?;
+        let start = Local.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+
+        let weekly: Vec<_> = cron
+            .iter_from(start)
+            .step_by_occurrences(7)
+            .take(4)
+            .collect();
+        let expected: Vec<_> = [1, 8, 15, 22]
+            .map(|day| Local.with_ymd_and_hms(2024, 1, day, 9, 0, 0).unwrap())
+            .to_vec();
+        assert_eq!(weekly, expected);
+        assert_eq!(
+            weekly,
+            cron.iter_from(start).step_by(7).take(4).collect::<Vec<_>>()
+        );
 
-    fn from_str(cron_string: &str) -> Result<Cron, CronError> {
-        let res = Cron::new(cron_string);
-        Ok(res)
+        // A bounded iterator ends when the next step passes the end
+        let end = Local.with_ymd_and_hms(2024, 1, 20, 0, 0, 0).unwrap();
+        let bounded = cron.iter_between(start, end).step_by_occurrences(7);
+        assert_eq!(bounded.count(), 3);
+        Ok(())
     }
-}
 
-#[cfg(feature = "serde")]
-impl Serialize for Cron {
-    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
-    where
-        S: Serializer,
-    {
-        serializer.serialize_str(self.pattern.as_str())
+    #[test]
+    #[should_panic(expected = "step of at least 1")]
+    fn test_step_by_occurrences_zero() {
+        let cron = Cron::new("0 9 * * *").parse().unwrap();
+        let _ = cron.iter_from(Local::now()).step_by_occurrences(0);
     }
-}
 
-#[cfg(feature = "serde")]
-impl<'de> Deserialize<'de> for Cron {
-    fn deserialize<D>(deserializer: D) -> Result<Cron, D::Error>
-    where
-        D: de::Deserializer<'de>,
-    {
-        struct CronVisitor;
+    #[test]
+    fn test_iter_until() -> Result<(), CronError> {
+        let cron = Cron::new("0 * * * *").parse()?;
+        let start = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        let end = Utc.with_ymd_and_hms(2024, 1, 1, 3, 0, 0).unwrap();
+        let hours = |times: Vec<DateTime<Utc>>| times.iter().map(|t| t.hour()).collect::<Vec<_>>();
 
-        impl Visitor<'_> for CronVisitor {
-            type Value = Cron;
+        assert_eq!(
+            hours(cron.iter_after(start).until(end, true).collect()),
+            [1, 2, 3]
+        );
+        assert_eq!(
+            hours(cron.iter_after(start).until(end, false).collect()),
+            [1, 2]
+        );
+        assert_eq!(
+            hours(cron.iter_from(start).until(end, false).collect()),
+            [0, 1, 2]
+        );
 
-            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
-                formatter.write_str("a valid cron pattern")
-            }
+        // Traversed from the end, the exclusive bound is skipped as well
+        assert_eq!(
+            hours(cron.iter_from(start).until(end, false).rev().collect()),
+            [2, 1, 0]
+        );
+        assert_eq!(
+            hours(cron.iter_from(start).until(end, true).rev().collect()),
+            [3, 2, 1, 0]
+        );
 
-            fn visit_str<E>(self, value: &str) -> Result<Cron, E>
-            where
-                E: de::Error,
-            {
-                Cron::new(value).parse().map_err(de::Error::custom)
-            }
-        }
+        let results: Vec<_> = cron.try_iter_after(start).until(end, false).collect();
+        assert_eq!(results.len(), 2);
+        Ok(())
+    }
 
-        deserializer.deserialize_str(CronVisitor)
+    #[test]
+    fn test_bounded_iter_after() -> Result<(), CronError> {
+        let start = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+
+        // A pattern that never matches ends quickly instead of searching until the year 5000
+        let cron = Cron::new("0 0 30 2 *").parse()?;
+        assert_eq!(cron.bounded_iter_after(start, 1000).count(), 0);
+
+        // A rarely matching pattern is found with enough searches, the budget spans the
+        // whole iteration
+        let cron = Cron::new("0 0 29 2 MON").with_dom_and_dow().parse()?;
+        assert_eq!(cron.bounded_iter_after(start, 10).next(), None);
+        let unbounded: Vec<_> = cron.iter_after(start).take(2).collect();
+        assert_eq!(
+            unbounded,
+            vec![
+                Utc.with_ymd_and_hms(2044, 2, 29, 0, 0, 0).unwrap(),
+                Utc.with_ymd_and_hms(2072, 2, 29, 0, 0, 0).unwrap(),
+            ]
+        );
+        let bounded: Vec<_> = cron.bounded_iter_after(start, 1_000_000).take(2).collect();
+        assert_eq!(bounded, unbounded);
+
+        // A frequent pattern uses a few searches per occurrence
+        let cron = Cron::new("* * * * *").parse()?;
+        let count = cron.bounded_iter_after(start, 100).count();
+        assert!(count > 0 && count < 100);
+        Ok(())
     }
-}
 
-// Recursive function to handle setting the time and managing overflows.
-#[allow(clippy::too_many_arguments)]
-fn set_time(
-    current_time: &mut NaiveDateTime,
-    year: i32,
-    month: u32,
-    day: u32,
-    hour: u32,
-    minute: u32,
-    second: u32,
-    component: TimeComponent,
-) -> Result<(), CronError> {
-    // First, try creating a NaiveDate and NaiveTime
-    match (
-        NaiveDate::from_ymd_opt(year, month, day),
-        NaiveTime::from_hms_opt(hour, minute, second),
-    ) {
-        (Some(date), Some(time)) => {
-            // Combine date and time into NaiveDateTime
-            *current_time = date.and_time(time);
-            Ok(())
-        }
-        _ => {
-            // Handle invalid date or overflow by incrementing the next higher component.
-            match component {
-                TimeComponent::Second => set_time(
-                    current_time,
-                    year,
-                    month,
-                    day,
-                    hour,
-                    minute + 1,
-                    0,
-                    TimeComponent::Minute,
-                ),
-                TimeComponent::Minute => set_time(
-                    current_time,
-                    year,
-                    month,
-                    day,
-                    hour + 1,
-                    0,
-                    0,
-                    TimeComponent::Hour,
-                ),
-                TimeComponent::Hour => set_time(
-                    current_time,
-                    year,
-                    month,
-                    day + 1,
-                    0,
-                    0,
-                    0,
-                    TimeComponent::Day,
-                ),
-                TimeComponent::Day => set_time(
-                    current_time,
-                    year,
-                    month + 1,
-                    1,
-                    0,
-                    0,
-                    0,
-                    TimeComponent::Month,
-                ),
-                TimeComponent::Month => {
-                    set_time(current_time, year + 1, 1, 1, 0, 0, 0, TimeComponent::Year)
-                }
-                TimeComponent::Year => Err(CronError::InvalidDate),
-            }
+    #[test]
+    fn test_iter_between_double_ended() -> Result<(), CronError> {
+        use chrono_tz::America::New_York;
+
+        // The range covers the fall back transition of 2024-11-03
+        let cron = Cron::new("0 */6 * * *").parse()?;
+        let start_time = New_York.with_ymd_and_hms(2024, 11, 2, 0, 0, 0).unwrap();
+        let end_time = New_York.with_ymd_and_hms(2024, 11, 4, 12, 0, 0).unwrap();
+
+        let forward: Vec<_> = cron.iter_between(start_time, end_time).collect();
+        let mut backward: Vec<_> = cron.iter_between(start_time, end_time).rev().collect();
+        backward.reverse();
+        assert_eq!(forward.len(), 11);
+        assert_eq!(forward, backward);
+
+        // Traversing from both ends meets in the middle without repeating times
+        let mut iter = cron.iter_between(start_time, end_time);
+        let mut times = Vec::new();
+        while let (Some(first), last) = (iter.next(), iter.next_back()) {
+            times.push(first);
+            times.extend(last);
         }
+        times.sort();
+        assert_eq!(times, forward);
+
+        Ok(())
     }
-}
 
-fn set_time_component(
-    current_time: &mut NaiveDateTime,
-    component: TimeComponent,
-    set_to: u32,
-) -> Result<(), CronError> {
-    // Extract all parts
-    let (year, month, day, hour, minute, _second) = (
-        current_time.year(),
-        current_time.month(),
-        current_time.day(),
-        current_time.hour(),
-        current_time.minute(),
-        current_time.second(),
-    );
+    #[test]
+    fn test_try_iter() -> Result<(), CronError> {
+        let cron = Cron::new("0 0 * * *").parse()?;
+        let start_time = Local.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
 
-    match component {
-        TimeComponent::Year => set_time(current_time, set_to as i32, 0, 0, 0, 0, 0, component),
-        TimeComponent::Month => set_time(current_time, year, set_to, 0, 0, 0, 0, component),
-        TimeComponent::Day => set_time(current_time, year, month, set_to, 0, 0, 0, component),
-        TimeComponent::Hour => set_time(current_time, year, month, day, set_to, 0, 0, component),
-        TimeComponent::Minute => {
-            set_time(current_time, year, month, day, hour, set_to, 0, component)
-        }
-        TimeComponent::Second => set_time(
-            current_time,
-            year,
-            month,
-            day,
-            hour,
-            minute,
-            set_to,
-            component,
-        ),
+        let times = cron
+            .try_iter_from(start_time)
+            .take(3)
+            .collect::<Result<Vec<_>, _>>()?;
+        assert_eq!(
+            times,
+            cron.iter_from(start_time).take(3).collect::<Vec<_>>()
+        );
+        let times = cron
+            .try_iter_after(start_time)
+            .take(3)
+            .collect::<Result<Vec<_>, _>>()?;
+        assert_eq!(
+            times,
+            cron.iter_after(start_time).take(3).collect::<Vec<_>>()
+        );
+
+        // Running out of occurrences is a regular end of iteration
+        let cron = Cron::new("0 0 30 2 *").parse()?;
+        assert!(cron.try_iter_from(start_time).next().is_none());
+
+        // So is running past the last representable time
+        let cron = Cron::new("* * * * * *").with_seconds_required().parse()?;
+        let mut iter = cron.try_iter_after(NaiveDateTime::MAX.and_utc());
+        assert!(iter.next().is_none());
+
+        Ok(())
     }
-}
 
-// Convert `NaiveDateTime` back to `DateTime<Tz>`
-pub fn from_naive<Tz: TimeZone>(
-    naive_time: NaiveDateTime,
-    timezone: &Tz,
-) -> Result<DateTime<Tz>, CronError> {
-    match timezone.from_local_datetime(&naive_time) {
-        chrono::LocalResult::Single(dt) => Ok(dt),
-        _ => Err(CronError::InvalidTime),
+    #[test]
+    fn test_time_until_next_and_since_previous() -> Result<(), CronError> {
+        use chrono_tz::America::New_York;
+
+        let cron = Cron::new("0 12 * * *").parse()?;
+        let time = Local.with_ymd_and_hms(2024, 1, 1, 12, 0, 0).unwrap();
+        assert_eq!(cron.time_until_next(&time)?, Duration::hours(24));
+        assert_eq!(cron.time_since_previous(&time)?, Duration::hours(24));
+
+        // Clocks move forward one hour at 02:00 on 2024-03-10 in New York
+        let time = New_York.with_ymd_and_hms(2024, 3, 10, 0, 0, 0).unwrap();
+        assert_eq!(cron.time_until_next(&time)?, Duration::hours(11));
+        let time = New_York.with_ymd_and_hms(2024, 3, 10, 13, 0, 0).unwrap();
+        assert_eq!(cron.time_since_previous(&time)?, Duration::hours(1));
+        let time = New_York.with_ymd_and_hms(2024, 3, 11, 0, 0, 0).unwrap();
+        assert_eq!(cron.time_since_previous(&time)?, Duration::hours(12));
+        let time = New_York.with_ymd_and_hms(2024, 3, 9, 23, 0, 0).unwrap();
+        assert_eq!(cron.time_until_next(&time)?, Duration::hours(12));
+
+        Ok(())
     }
-}
 
-fn increment_time_component(
-    current_time: &mut NaiveDateTime,
-    component: TimeComponent,
-) -> Result<(), CronError> {
-    // Check for time overflow
-    if current_time.year() >= YEAR_UPPER_LIMIT {
-        return Err(CronError::TimeSearchLimitExceeded);
+    #[test]
+    fn test_count_between() -> Result<(), CronError> {
+        use chrono_tz::America::New_York;
+
+        let cron = Cron::new("* * * * *").parse()?;
+        let start = Local.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        let end = Local.with_ymd_and_hms(2024, 2, 1, 0, 0, 0).unwrap();
+        assert_eq!(cron.count_between(&start, &end, true)?, 31 * 24 * 60 + 1);
+        assert_eq!(cron.count_between(&start, &end, false)?, 31 * 24 * 60 - 1);
+        assert_eq!(cron.count_between(&end, &start, true)?, 0);
+
+        // Matches the number of occurrences found by iteration
+        let start = Local.with_ymd_and_hms(2024, 2, 27, 13, 17, 29).unwrap();
+        let end = Local.with_ymd_and_hms(2024, 3, 2, 9, 42, 31).unwrap();
+        for pattern in [
+            "*/7 8-18/3 * * *",
+            "0,30 * * * * *",
+            "0 12 L * *",
+            "15 * * * FRI#1",
+        ] {
+            let cron = Cron::new(pattern).with_seconds_optional().parse()?;
+            let expected = cron.iter_between(start, end).count() as u64;
+            assert_eq!(
+                cron.count_between(&start, &end, true)?,
+                expected,
+                "{pattern}"
+            );
+        }
+
+        // Clocks skip from 02:00 to 03:00 on 2024-03-10 in New York
+        let cron = Cron::new("0 * * * *").parse()?;
+        let start = New_York.with_ymd_and_hms(2024, 3, 10, 0, 0, 0).unwrap();
+        let end = New_York.with_ymd_and_hms(2024, 3, 10, 23, 59, 0).unwrap();
+        assert_eq!(cron.count_between(&start, &end, true)?, 23);
+
+        Ok(())
     }
 
-    // Extract all parts
-    let (year, month, day, hour, minute, second) = (
-        current_time.year(),
-        current_time.month(),
-        current_time.day(),
-        current_time.hour(),
-        current_time.minute(),
-        current_time.second(),
-    );
+    #[test]
+    fn test_next_crosses_dst() -> Result<(), CronError> {
+        use chrono_tz::America::New_York;
 
-    // Increment the component and try to set the new time.
-    match component {
-        TimeComponent::Year => set_time(current_time, year + 1, 1, 1, 0, 0, 0, component),
-        TimeComponent::Month => set_time(current_time, year, month + 1, 1, 0, 0, 0, component),
-        TimeComponent::Day => set_time(current_time, year, month, day + 1, 0, 0, 0, component),
-        TimeComponent::Hour => set_time(current_time, year, month, day, hour + 1, 0, 0, component),
-        TimeComponent::Minute => set_time(
-            current_time,
-            year,
-            month,
-            day,
-            hour,
-            minute + 1,
-            0,
-            component,
-        ),
-        TimeComponent::Second => set_time(
-            current_time,
-            year,
-            month,
-            day,
-            hour,
-            minute,
-            second + 1,
-            component,
-        ),
+        // Clocks move forward one hour at 02:00 on 2024-03-10 in New York
+        let cron = Cron::new("0 12 * * *").parse()?;
+        let time = New_York.with_ymd_and_hms(2024, 3, 9, 12, 0, 0).unwrap();
+        assert!(cron.next_crosses_dst(&time)?);
+        let time = New_York.with_ymd_and_hms(2024, 3, 10, 12, 0, 0).unwrap();
+        assert!(!cron.next_crosses_dst(&time)?);
+
+        // Fixed offsets never cross a transition
+        let time = Local.with_ymd_and_hms(2024, 3, 9, 12, 0, 0).unwrap();
+        assert!(!cron.next_crosses_dst(&time.with_timezone(&Utc))?);
+
+        Ok(())
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use chrono::{Local, TimeZone};
-    #[cfg(feature = "serde")]
-    use serde_test::{assert_de_tokens_error, assert_tokens, Token};
     #[test]
-    fn test_is_time_matching() -> Result<(), CronError> {
-        // This pattern is meant to match first second of 9 am on the first day of January.
-        let cron = Cron::new("0 9 1 1 *").parse()?;
-        let time_matching = Local.with_ymd_and_hms(2023, 1, 1, 9, 0, 0).unwrap();
-        let time_not_matching = Local.with_ymd_and_hms(2023, 1, 1, 10, 0, 0).unwrap();
+    fn test_dst_gap_policy() -> Result<(), CronError> {
+        use chrono_tz::America::New_York;
 
-        assert!(cron.is_time_matching(&time_matching)?);
-        assert!(!cron.is_time_matching(&time_not_matching)?);
+        // Clocks skip from 02:00 to 03:00 on 2024-03-10 in New York
+        let start_time = New_York.with_ymd_and_hms(2024, 3, 10, 0, 0, 0).unwrap();
+
+        let cron = Cron::new("30 2 * * *").parse()?;
+        let expected = New_York.with_ymd_and_hms(2024, 3, 10, 3, 0, 0).unwrap();
+        assert_eq!(cron.find_next_occurrence(&start_time, false)?, expected);
+
+        let cron = Cron::new("30 2 * * *")
+            .with_dst_gap(DstGapPolicy::Skip)
+            .parse()?;
+        let expected = New_York.with_ymd_and_hms(2024, 3, 11, 2, 30, 0).unwrap();
+        assert_eq!(cron.find_next_occurrence(&start_time, false)?, expected);
+
+        // Times snapped to the end of the gap are only yielded once
+        let cron = Cron::new("*/20 * * * *").parse()?;
+        let times: Vec<_> = cron.iter_from(start_time).skip(6).take(3).collect();
+        let expected: Vec<_> = [(3, 0), (3, 20), (3, 40)]
+            .iter()
+            .map(|(h, m)| New_York.with_ymd_and_hms(2024, 3, 10, *h, *m, 0).unwrap())
+            .collect();
+        assert_eq!(times, expected);
 
         Ok(())
     }
 
     #[test]
-    fn test_last_day_of_february_non_leap_year() -> Result<(), CronError> {
-        // This pattern is meant to match every second of 9 am on the last day of February in a non-leap year.
-        let cron = Cron::new("0 9 L 2 *").parse()?;
+    fn test_dst_overlap_policy_interval() -> Result<(), CronError> {
+        use chrono_tz::Europe::Stockholm;
+
+        // Clocks move back from 03:00 to 02:00 on 2024-10-27 in Stockholm, so the wall-clock
+        // hour 02:00-02:59 lasts from 00:00 to 02:00 UTC
+        let start_time = Utc
+            .with_ymd_and_hms(2024, 10, 27, 0, 0, 0)
+            .unwrap()
+            .with_timezone(&Stockholm);
+        let end_time = Utc
+            .with_ymd_and_hms(2024, 10, 27, 1, 59, 0)
+            .unwrap()
+            .with_timezone(&Stockholm);
+
+        // Fires once per wall-clock minute by default, and in both passes with FireBoth
+        for (policy, expected) in [
+            (DstOverlapPolicy::FireFirst, 60),
+            (DstOverlapPolicy::FireBoth, 120),
+            (DstOverlapPolicy::FireLast, 60),
+        ] {
+            let cron = Cron::new("* * * * *").with_dst_overlap(policy).parse()?;
+            let times: Vec<_> = cron.iter_between(start_time, end_time).collect();
+            assert_eq!(times.len(), expected, "{policy:?}");
+            let wall_clock: std::collections::BTreeSet<_> =
+                times.iter().map(|t| t.naive_local()).collect();
+            assert_eq!(wall_clock.len(), 60, "{policy:?}");
+        }
+        Ok(())
+    }
 
-        // February 28th, 2023 is the last day of February in a non-leap year.
-        let time_matching = Local.with_ymd_and_hms(2023, 2, 28, 9, 0, 0).unwrap();
-        let time_not_matching = Local.with_ymd_and_hms(2023, 2, 28, 10, 0, 0).unwrap();
-        let time_not_matching_2 = Local.with_ymd_and_hms(2023, 2, 27, 9, 0, 0).unwrap();
+    #[test]
+    fn test_dst_overlap_policy() -> Result<(), CronError> {
+        use chrono_tz::America::New_York;
+
+        // Clocks move back from 02:00 to 01:00 on 2024-11-03 in New York
+        let start_time = New_York.with_ymd_and_hms(2024, 11, 3, 0, 0, 0).unwrap();
+        let end_time = New_York.with_ymd_and_hms(2024, 11, 3, 3, 0, 0).unwrap();
+        let utc_hours = |times: Vec<DateTime<_>>| -> Vec<String> {
+            times
+                .iter()
+                .map(|t: &DateTime<chrono_tz::Tz>| {
+                    t.with_timezone(&Utc).format("%H:%M").to_string()
+                })
+                .collect()
+        };
+
+        for (policy, expected) in [
+            (DstOverlapPolicy::FireFirst, vec!["05:30"]),
+            (DstOverlapPolicy::FireBoth, vec!["05:30", "06:30"]),
+            (DstOverlapPolicy::FireLast, vec!["06:30"]),
+        ] {
+            let cron = Cron::new("30 1 * * *").with_dst_overlap(policy).parse()?;
+            let forward: Vec<_> = cron.iter_between(start_time, end_time).collect();
+            let mut backward: Vec<_> = cron.iter_between(start_time, end_time).rev().collect();
+            backward.reverse();
+            assert_eq!(utc_hours(forward.clone()), expected, "{policy:?}");
+            assert_eq!(forward, backward, "{policy:?}");
+            assert_eq!(
+                cron.count_between(&start_time, &end_time, true)?,
+                expected.len() as u64
+            );
+        }
 
-        assert!(cron.is_time_matching(&time_matching)?);
-        assert!(!cron.is_time_matching(&time_not_matching)?);
-        assert!(!cron.is_time_matching(&time_not_matching_2)?);
+        // Firing both passes of several repeated times keeps the occurrences in order
+        let cron = Cron::new("*/30 1 * * *")
+            .with_dst_overlap(DstOverlapPolicy::FireBoth)
+            .parse()?;
+        let forward: Vec<_> = cron.iter_between(start_time, end_time).collect();
+        let mut backward: Vec<_> = cron.iter_between(start_time, end_time).rev().collect();
+        backward.reverse();
+        assert_eq!(
+            utc_hours(forward.clone()),
+            vec!["05:00", "05:30", "06:00", "06:30"]
+        );
+        assert_eq!(forward, backward);
+
+        // Dense patterns fire every second of both passes, in order and without rescanning the
+        // repeated hour for every occurrence
+        let cron = Cron::new("* * * * * *")
+            .with_seconds_required()
+            .with_dst_overlap(DstOverlapPolicy::FireBoth)
+            .parse()?;
+        let (first, last) = (
+            New_York
+                .with_ymd_and_hms(2024, 11, 3, 1, 0, 0)
+                .earliest()
+                .unwrap(),
+            New_York
+                .with_ymd_and_hms(2024, 11, 3, 1, 59, 59)
+                .latest()
+                .unwrap(),
+        );
+        let searches = std::time::Instant::now();
+        let forward: Vec<_> = cron.iter_between(first, last).collect();
+        let mut backward: Vec<_> = cron.iter_between(first, last).rev().collect();
+        assert!(searches.elapsed() < std::time::Duration::from_secs(10));
+        backward.reverse();
+        assert_eq!(forward.len(), 7200);
+        assert!(forward.windows(2).all(|pair| pair[0] < pair[1]));
+        assert_eq!(forward, backward);
+
+        // Starting within the first pass of the repeated hour, the second pass still lies ahead
+        let cron = Cron::new("0 * * * *")
+            .with_dst_overlap(DstOverlapPolicy::FireBoth)
+            .parse()?;
+        let first_pass = New_York
+            .with_ymd_and_hms(2024, 11, 3, 1, 40, 0)
+            .earliest()
+            .unwrap();
+        let next = cron.find_next_occurrence(&first_pass, false)?;
+        assert_eq!(
+            next.with_timezone(&Utc).format("%H:%M").to_string(),
+            "06:00"
+        );
+        assert_eq!(cron.find_previous_occurrence(&next, false)?.hour(), 1);
+        assert_eq!(
+            cron.find_previous_occurrence(&next, false)?
+                .with_timezone(&Utc)
+                .hour(),
+            5
+        );
 
         Ok(())
     }
 
     #[test]
-    fn test_last_day_of_february_leap_year() -> Result<(), CronError> {
-        // This pattern is meant to match every second of 9 am on the last day of February in a leap year.
-        let cron = Cron::new("0 9 L 2 *").parse()?;
+    fn test_equality() -> Result<(), CronError> {
+        use std::collections::HashSet;
 
-        // February 29th, 2024 is the last day of February in a leap year.
-        let time_matching = Local.with_ymd_and_hms(2024, 2, 29, 9, 0, 0).unwrap();
-        let time_not_matching = Local.with_ymd_and_hms(2024, 2, 29, 10, 0, 0).unwrap();
-        let time_not_matching_2 = Local.with_ymd_and_hms(2024, 2, 28, 9, 0, 0).unwrap();
+        let parse = |pattern: &str, dom_and_dow: bool| -> Result<Cron, CronError> {
+            let mut cron = Cron::new(pattern);
+            if dom_and_dow {
+                cron.with_dom_and_dow();
+            }
+            cron.parse()
+        };
 
-        assert!(cron.is_time_matching(&time_matching)?);
-        assert!(!cron.is_time_matching(&time_not_matching)?);
-        assert!(!cron.is_time_matching(&time_not_matching_2)?);
+        // The first Monday of the month, written as a range of days or as an nth weekday
+        let range = parse("0 0 1-7 * 1", true)?;
+        let nth = parse("0 0 * * 1#1", false)?;
+        assert_eq!(range, nth);
+        assert_eq!(
+            parse("0 0 22-28 * MON-FRI", true)?,
+            parse("0 0 * * 1#4,2#4,3#4,4#4,5#4", false)?
+        );
+        assert_eq!(HashSet::from([range.clone(), nth.clone()]).len(), 1);
+        assert_eq!(range.canonical(), nth.canonical());
+        assert_eq!(range.canonical(), "0 0 0 * * 1#1");
+
+        // Equality follows the canonical fields, which are not merged across each other
+        assert_ne!(parse("0 0 1-31 * *", false)?, parse("0 0 * * *", false)?);
+
+        // Combined using OR, the range matches every Monday and the first seven days
+        assert_ne!(parse("0 0 1-7 * 1", false)?, nth);
+        assert_ne!(parse("0 0 2-8 * 1", true)?, nth);
+        assert_ne!(parse("0 0 1-6,8 * 1", true)?, nth);
+
+        // Different spellings of the same schedule
+        assert_eq!(parse("@daily", false)?, parse("0 0 * * *", false)?);
+        assert_eq!(parse("0 0 * * 7", false)?, parse("0 0 * * SUN", false)?);
+        assert_ne!(parse("0 0 13 * FRI", true)?, parse("0 0 13 * FRI", false)?);
+        assert_ne!(
+            parse("30 1 * * *", false)?,
+            parse("30 1 * * *", false)?
+                .with_dst_overlap(DstOverlapPolicy::FireBoth)
+                .parse()?
+        );
+        Ok(())
+    }
 
+    #[test]
+    fn test_matches_any_in() -> Result<(), CronError> {
+        let leap_day = Cron::new("0 0 29 2 *").parse()?;
+        let at = |year, month, day| Utc.with_ymd_and_hms(year, month, day, 0, 0, 0).unwrap();
+
+        // February 2023 has no 29th, February 2024 does
+        assert!(!leap_day.matches_any_in(&at(2023, 2, 1), &at(2023, 2, 28))?);
+        assert!(leap_day.matches_any_in(&at(2024, 2, 1), &at(2024, 2, 29))?);
+
+        // Both ends are included, and an inverted window matches nothing
+        assert!(leap_day.matches_any_in(&at(2024, 2, 29), &at(2024, 2, 29))?);
+        assert!(!leap_day.matches_any_in(&at(2024, 3, 1), &at(2024, 2, 1))?);
+
+        let every_second = Cron::new("* * * * * *").with_seconds_required().parse()?;
+        assert!(every_second.matches_any_in(&at(2024, 1, 1), &at(2024, 12, 31))?);
+
+        // The search ends at the end of the window, rather than at the search limit
+        let never = Cron::new("0 0 30 2 *").parse()?;
+        let searches = std::time::Instant::now();
+        for day in 1..=28 {
+            assert!(!never.matches_any_in(&at(2024, 2, day), &at(2024, 2, day))?);
+        }
+        assert!(searches.elapsed() < std::time::Duration::from_secs(1));
+
+        // A window ending in the later pass of a repeated hour includes the earlier pass
+        use chrono_tz::America::New_York;
+        let cron = Cron::new("50 1 * * *").parse()?;
+        let (start, end) = match New_York.with_ymd_and_hms(2024, 11, 3, 1, 10, 0) {
+            LocalResult::Ambiguous(earliest, latest) => (earliest, latest),
+            _ => unreachable!("01:10 is repeated on 2024-11-03"),
+        };
+        assert!(cron.matches_any_in(&start, &end)?);
         Ok(())
     }
 
     #[test]
-    fn test_last_friday_of_year() -> Result<(), CronError> {
-        // This pattern is meant to match 0:00:00 last friday of current year
-        let cron = Cron::new("0 0 * * FRI#L").parse()?;
+    fn test_align() -> Result<(), CronError> {
+        let cron = Cron::new("0 */6 * * *").parse()?;
+        let at = |day, hour, minute| Utc.with_ymd_and_hms(2024, 1, day, hour, minute, 0).unwrap();
 
-        // February 29th, 2024 is the last day of February in a leap year.
-        let time_matching = Local.with_ymd_and_hms(2023, 12, 29, 0, 0, 0).unwrap();
+        assert_eq!(
+            cron.align(&at(1, 7, 30), SearchDirection::Forward)?,
+            at(1, 12, 0)
+        );
+        assert_eq!(
+            cron.align(&at(1, 7, 30), SearchDirection::Backward)?,
+            at(1, 6, 0)
+        );
+        assert_eq!(
+            cron.align(&at(1, 23, 59), SearchDirection::Forward)?,
+            at(2, 0, 0)
+        );
 
-        assert!(cron.is_time_matching(&time_matching)?);
+        // A matching time stays where it is, unlike with the exclusive searches
+        assert_eq!(
+            cron.align(&at(1, 6, 0), SearchDirection::Forward)?,
+            at(1, 6, 0)
+        );
+        assert_eq!(
+            cron.align(&at(1, 6, 0), SearchDirection::Backward)?,
+            at(1, 6, 0)
+        );
+        assert_eq!(
+            cron.find_next_occurrence(&at(1, 6, 0), false)?,
+            at(1, 12, 0)
+        );
+
+        // Sub-second parts are aligned as well
+        let with_millis = at(1, 6, 0) + Duration::milliseconds(250);
+        assert_eq!(
+            cron.align(&with_millis, SearchDirection::Forward)?,
+            at(1, 12, 0)
+        );
+        assert_eq!(
+            cron.align(&with_millis, SearchDirection::Backward)?,
+            at(1, 6, 0)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_search_at_year_limits() -> Result<(), CronError> {
+        let patterns = [
+            "* * * * * *",
+            "0 0 0 1 1 *",
+            "59 59 23 31 12 *",
+            "0 0 0 29 2 *",
+            "0 0 0 1W * *",
+            "0 0 0 * * 5L",
+            "0 0 0 * * 1#1",
+        ];
+        let upper = Utc.with_ymd_and_hms(5000, 12, 31, 23, 59, 59).unwrap();
+        let lower = Utc.with_ymd_and_hms(1, 1, 1, 0, 0, 0).unwrap();
+
+        for pattern in patterns {
+            let cron = Cron::new(pattern).with_seconds_required().parse()?;
+            assert!(
+                matches!(
+                    cron.find_next_occurrence(&upper, false),
+                    Err(CronError::TimeSearchLimitExceeded)
+                ),
+                "{pattern}"
+            );
+            assert!(
+                matches!(
+                    cron.find_previous_occurrence(&lower, false),
+                    Err(CronError::TimeSearchLimitExceeded)
+                ),
+                "{pattern}"
+            );
+
+            // Searching from the extremes chrono can represent does not panic either
+            assert!(matches!(
+                cron.find_next_occurrence(&DateTime::<Utc>::MAX_UTC, true),
+                Err(CronError::TimeSearchLimitExceeded)
+            ));
+            assert!(matches!(
+                cron.find_previous_occurrence(&DateTime::<Utc>::MIN_UTC, true),
+                Err(CronError::TimeSearchLimitExceeded)
+            ));
+        }
 
+        // The last second of the year 5000 is still found by an inclusive search
+        let cron = Cron::new("59 59 23 31 12 *")
+            .with_seconds_required()
+            .parse()?;
+        assert_eq!(cron.find_next_occurrence(&upper, true)?, upper);
         Ok(())
     }
 
     #[test]
-    fn test_last_friday_of_year_alternative_alpha_syntax() -> Result<(), CronError> {
-        // This pattern is meant to match 0:00:00 last friday of current year
-        let cron = Cron::new("0 0 * * FRIl").parse()?;
+    fn test_find_nth_occurrence() -> Result<(), CronError> {
+        use chrono_tz::America::New_York;
+
+        let cron = Cron::new("0 12 * * MON").parse()?;
+        let start = Utc.with_ymd_and_hms(2024, 1, 1, 12, 0, 0).unwrap(); // A Monday
+        let forward =
+            |k, inclusive| cron.find_nth_occurrence(&start, k, inclusive, SearchDirection::Forward);
+        let backward = |k, inclusive| {
+            cron.find_nth_occurrence(&start, k, inclusive, SearchDirection::Backward)
+        };
+
+        assert_eq!(forward(0, true)?, start);
+        assert_eq!(
+            forward(0, false)?,
+            Utc.with_ymd_and_hms(2024, 1, 8, 12, 0, 0).unwrap()
+        );
+        assert_eq!(
+            forward(3, true)?,
+            Utc.with_ymd_and_hms(2024, 1, 22, 12, 0, 0).unwrap()
+        );
+        assert_eq!(
+            backward(1, false)?,
+            Utc.with_ymd_and_hms(2023, 12, 18, 12, 0, 0).unwrap()
+        );
+        for k in [0, 1, 10, 52] {
+            assert_eq!(Some(forward(k, true)?), cron.iter_from(start).nth(k));
+        }
 
-        // February 29th, 2024 is the last day of February in a leap year.
-        let time_matching = Local.with_ymd_and_hms(2023, 12, 29, 0, 0, 0).unwrap();
+        // The search limit surfaces as an error instead of ending the search
+        assert!(matches!(
+            cron.find_nth_occurrence(
+                &Utc.with_ymd_and_hms(4999, 12, 1, 0, 0, 0).unwrap(),
+                10,
+                false,
+                SearchDirection::Forward
+            ),
+            Err(CronError::TimeSearchLimitExceeded)
+        ));
+
+        // Both passes of a repeated time count when firing both
+        let cron = Cron::new("30 1 * * *")
+            .with_dst_overlap(DstOverlapPolicy::FireBoth)
+            .parse()?;
+        let start = New_York.with_ymd_and_hms(2024, 11, 3, 0, 0, 0).unwrap();
+        let second = cron.find_nth_occurrence(&start, 1, false, SearchDirection::Forward)?;
+        assert_eq!(second, Utc.with_ymd_and_hms(2024, 11, 3, 6, 30, 0).unwrap());
+        let third = cron.find_nth_occurrence(&start, 2, false, SearchDirection::Forward)?;
+        assert_eq!(third, Utc.with_ymd_and_hms(2024, 11, 4, 6, 30, 0).unwrap());
+        Ok(())
+    }
 
-        assert!(cron.is_time_matching(&time_matching)?);
+    #[test]
+    fn test_is_equivalent_to() -> Result<(), CronError> {
+        let parse = |pattern: &str| Cron::new(pattern).parse();
+        let start = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        let end = Utc.with_ymd_and_hms(2027, 12, 31, 0, 0, 0).unwrap();
+
+        // The first Monday of the month, written as a range and as an nth weekday
+        let range = Cron::new("0 0 1-7 * MON").with_dom_and_dow().parse()?;
+        let nth = parse("0 0 * * MON#1")?;
+        assert!(range.is_equivalent_to(&nth, &start, &end)?);
+        assert!(nth.is_equivalent_to(&range, &start, &end)?);
+
+        // Combined using OR, the range also matches the first seven days
+        let either = parse("0 0 1-7 * MON")?;
+        assert!(!either.is_equivalent_to(&nth, &start, &end)?);
+
+        // Equivalent only within a window, L and 31 agree in months with 31 days
+        let last = parse("0 0 L 1 *")?;
+        let thirty_first = parse("0 0 31 1 *")?;
+        assert!(last.is_equivalent_to(&thirty_first, &start, &end)?);
+        let last = parse("0 0 L 4 *")?;
+        let thirtieth = parse("0 0 30 4 *")?;
+        assert!(last.is_equivalent_to(&thirtieth, &start, &end)?);
+        assert!(!parse("0 0 L * *")?.is_equivalent_to(&parse("0 0 31 * *")?, &start, &end)?);
+
+        // Times of day and policies count as well
+        assert!(!parse("0 0 * * *")?.is_equivalent_to(&parse("0 1 * * *")?, &start, &end)?);
+        let fire_both = Cron::new("0 0 * * *")
+            .with_dst_overlap(DstOverlapPolicy::FireBoth)
+            .parse()?;
+        assert!(!parse("0 0 * * *")?.is_equivalent_to(&fire_both, &start, &end)?);
+
+        // Only the times within the window are compared, also on the days at its ends
+        let morning = parse("0 9 * * *")?;
+        let twice = parse("0 9,18 * * *")?;
+        let day = |hour, minute| Utc.with_ymd_and_hms(2024, 1, 1, hour, minute, 0).unwrap();
+        assert!(morning.is_equivalent_to(&twice, &day(0, 0), &day(17, 59))?);
+        assert!(!morning.is_equivalent_to(&twice, &day(0, 0), &day(18, 0))?);
+        assert!(morning.is_equivalent_to(&parse("0 9 2 * *")?, &day(9, 1), &day(23, 59))?);
+        assert!(!morning.is_equivalent_to(&parse("0 9 2 * *")?, &day(9, 0), &day(23, 59))?);
+        assert!(!parse("0 9 2 * *")?.is_equivalent_to(&morning, &day(9, 0), &day(23, 59))?);
+
+        // Patterns never matching within the window are equivalent there
+        let leap_day = parse("0 0 29 2 *")?;
+        let never = parse("0 12 30 2 *")?;
+        let start = Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap();
+        let end = Utc.with_ymd_and_hms(2027, 12, 31, 0, 0, 0).unwrap();
+        assert!(leap_day.is_equivalent_to(&never, &start, &end)?);
+        Ok(())
+    }
 
+    #[test]
+    fn test_find_previous_n() -> Result<(), CronError> {
+        let cron = Cron::new("0 0 1 * *").parse()?;
+        let start = Utc.with_ymd_and_hms(2024, 3, 1, 0, 0, 0).unwrap();
+        let months =
+            |times: Vec<DateTime<Utc>>| times.iter().map(|t| t.month()).collect::<Vec<_>>();
+
+        assert_eq!(months(cron.find_previous_n(&start, 3, true)?), [3, 2, 1]);
+        assert_eq!(months(cron.find_previous_n(&start, 3, false)?), [2, 1, 12]);
+        assert!(cron.find_previous_n(&start, 0, false)?.is_empty());
+
+        // Mirrors find_next_n
+        let mut forward = cron.find_next_n(
+            &Utc.with_ymd_and_hms(2023, 11, 15, 0, 0, 0).unwrap(),
+            4,
+            false,
+        )?;
+        forward.reverse();
+        assert_eq!(cron.find_previous_n(&start, 4, true)?, forward);
+
+        // Running into the lower year limit is an error rather than a shorter list
+        let near_limit = Utc.with_ymd_and_hms(2, 3, 15, 0, 0, 0).unwrap();
+        assert_eq!(cron.find_previous_n(&near_limit, 3, false)?.len(), 3);
+        assert!(matches!(
+            cron.find_previous_n(&near_limit, 4, false),
+            Err(CronError::TimeSearchLimitExceeded)
+        ));
         Ok(())
     }
 
     #[test]
-    fn test_last_friday_of_year_alternative_number_syntax() -> Result<(), CronError> {
-        // This pattern is meant to match 0:00:00 last friday of current year
-        let cron = Cron::new("0 0 * * 5L").parse()?;
+    fn test_fixed_time_of_day() -> Result<(), CronError> {
+        let fixed_time = |pattern: &str| -> Result<_, CronError> {
+            Ok(Cron::new(pattern)
+                .with_seconds_optional()
+                .parse()?
+                .fixed_time_of_day())
+        };
+        assert_eq!(
+            fixed_time("0 30 9 * * *")?,
+            NaiveTime::from_hms_opt(9, 30, 0)
+        );
+        assert_eq!(
+            fixed_time("15 0 0 L * *")?,
+            NaiveTime::from_hms_opt(0, 0, 15)
+        );
+        assert_eq!(fixed_time("@daily")?, NaiveTime::from_hms_opt(0, 0, 0));
+        assert_eq!(fixed_time("*/5 * * * *")?, None);
+        assert_eq!(fixed_time("0 9,17 * * *")?, None);
+        assert_eq!(fixed_time("* 0 0 * * *")?, None);
+        Ok(())
+    }
 
-        // February 29th, 2024 is the last day of February in a leap year.
-        let time_matching = Local.with_ymd_and_hms(2023, 12, 29, 0, 0, 0).unwrap();
+    #[test]
+    fn test_period() -> Result<(), CronError> {
+        for (pattern, expected) in [
+            ("0 0 * * MON", Some(Duration::days(7))),
+            ("*/10 9-17 * * MON-FRI", Some(Duration::days(7))),
+            ("0 0 * * *", Some(Duration::days(1))),
+            ("0 0 * * SUN-SAT", Some(Duration::days(1))),
+            ("*/15 * * * *", Some(Duration::minutes(15))),
+            ("0 */6 * * *", Some(Duration::hours(6))),
+            ("0,30 * * * * *", Some(Duration::seconds(30))),
+            ("0 9,17 * * *", Some(Duration::days(1))),
+            ("*/7 * * * *", Some(Duration::hours(1))),
+            ("0 0 1 * *", None),
+            ("0 0 * 6 *", None),
+            ("0 0 * * 5L", None),
+            ("0 0 * * 2#2", None),
+        ] {
+            let cron = Cron::new(pattern).with_seconds_optional().parse()?;
+            assert_eq!(cron.period(), expected, "{pattern}");
+        }
+        Ok(())
+    }
 
-        assert!(cron.is_time_matching(&time_matching)?);
+    #[test]
+    fn test_interval_is_uniform() -> Result<(), CronError> {
+        for (pattern, expected) in [
+            ("*/7 * * * * *", false),
+            ("*/5 * * * * *", true),
+            ("*/15 * * * * *", true),
+            ("0 */7 * * * *", false),
+            ("0 */15 * * * *", true),
+            ("*/15 */2 * * * *", false),
+            ("0 0 */5 * * *", false),
+            ("0 0 */6 * * *", true),
+            ("0 0 0 * * *", true),
+            ("0 0 0 * * MON", true),
+            ("0 0 0 * * MON,WED", false),
+            ("0 0 0 1 * *", false),
+        ] {
+            let cron = Cron::new(pattern).with_seconds_required().parse()?;
+            assert_eq!(cron.interval_is_uniform(), expected, "{pattern}");
+        }
+        Ok(())
+    }
 
+    #[test]
+    fn test_min_interval() -> Result<(), CronError> {
+        for (pattern, expected) in [
+            ("* * * * * *", Some(Duration::seconds(1))),
+            ("0 */5 * * * *", Some(Duration::minutes(5))),
+            ("*/7 * * * * *", Some(Duration::seconds(4))),
+            ("0 0 9 * * *", Some(Duration::days(1))),
+            ("0 0 0,23 * * *", Some(Duration::hours(1))),
+            ("0 0 9 * * MON-FRI", Some(Duration::days(1))),
+            ("0 0 9 * * MON", Some(Duration::days(7))),
+            ("0 30 9 * * SAT,MON", Some(Duration::days(2))),
+            ("0 0 0 1 * *", Some(Duration::days(28))),
+            ("0 0 0 L,1 * *", Some(Duration::days(1))),
+            ("0 0 0 L-2 * *", Some(Duration::days(28))),
+            ("0 0 0 29 2,3 *", Some(Duration::days(29))),
+            ("0 0 0 1 1 *", Some(Duration::days(365))),
+            ("0 0 0 29 2 *", Some(Duration::days(4 * 365 + 1))),
+            ("0 0 0 30 2 *", None),
+        ] {
+            let cron = Cron::new(pattern).with_seconds_required().parse()?;
+            assert_eq!(cron.min_interval(), expected, "{pattern}");
+        }
         Ok(())
     }
 
     #[test]
-    fn test_find_next_occurrence() -> Result<(), CronError> {
-        // This pattern is meant to match every minute at 30 seconds past the minute.
-        let cron = Cron::new("* * * * * *").with_seconds_optional().parse()?;
+    fn test_as_fields() -> Result<(), CronError> {
+        let cron = Cron::new("30 12 * * *").parse()?;
+        assert_eq!(cron.as_fields(), ["0", "30", "12", "*", "*", "*", "*"]);
 
-        // Set the start time to a known value.
-        let start_time = Local.with_ymd_and_hms(2023, 1, 1, 0, 0, 29).unwrap();
-        // Calculate the next occurrence from the start time.
-        let next_occurrence = cron.find_next_occurrence(&start_time, false)?;
+        let cron = Cron::new("5 0,15,30,45 * L JAN-MAR SUN#2")
+            .with_seconds_required()
+            .parse()?;
+        assert_eq!(cron.as_fields(), ["5", "*/15", "*", "L", "1-3", "0#2", "*"]);
 
-        // Verify that the next occurrence is at the expected time.
-        let expected_time = Local.with_ymd_and_hms(2023, 1, 1, 0, 0, 30).unwrap();
-        assert_eq!(next_occurrence, expected_time);
+        let cron = Cron::new("0 15 10 ? * MON-FRI *").with_quartz().parse()?;
+        assert_eq!(cron.as_fields(), ["0", "15", "10", "*", "*", "1-5", "*"]);
+
+        // The fields put together are the canonical pattern
+        assert_eq!(cron.as_fields()[..6].join(" "), cron.canonical());
+        Ok(())
+    }
+
+    #[test]
+    fn test_iter_after_reported() -> Result<(), CronError> {
+        let start_time = Local.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+
+        let cron = Cron::new("0 0 * * 2#2").parse()?;
+        for occurrence in cron.iter_after_reported(start_time).take(6) {
+            let (time, report) = occurrence?;
+            assert_eq!(time.weekday(), chrono::Weekday::Tue);
+            assert_eq!(
+                report,
+                MatchReport {
+                    nth_weekday: true,
+                    ..MatchReport::default()
+                }
+            );
+        }
+
+        // Either field may trigger a match when both are restricted
+        let cron = Cron::new("0 0 L * FRI").parse()?;
+        let reports = cron
+            .iter_after_reported(start_time)
+            .take(5)
+            .collect::<Result<Vec<_>, _>>()?;
+        let dates: Vec<_> = reports.iter().map(|(time, _)| time.day()).collect();
+        assert_eq!(dates, [5, 12, 19, 26, 31]);
+        assert!(reports[..4].iter().all(|(_, report)| report.day_of_week));
+        assert!(reports[4].1.last_day_of_month && !reports[4].1.day_of_week);
+
+        let time = Local.with_ymd_and_hms(2024, 1, 2, 0, 0, 0).unwrap();
+        assert_eq!(cron.match_report(&time)?, None);
+
+        // Occurrences moved forward out of a DST gap are reported, not dropped
+        use chrono_tz::America::New_York;
+        let cron = Cron::new("30 2 * * SUN").parse()?;
+        let start_time = New_York.with_ymd_and_hms(2024, 3, 9, 0, 0, 0).unwrap();
+        let (time, report) = cron.iter_after_reported(start_time).next().unwrap()?;
+        assert_eq!(
+            time,
+            New_York.with_ymd_and_hms(2024, 3, 10, 3, 0, 0).unwrap()
+        );
+        assert!(report.day_of_week);
 
         Ok(())
     }
@@ -942,7 +4108,7 @@ mod tests {
         let start_date = Local.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
 
         // Define the expected matching dates
-        let expected_dates = vec![
+        let expected_dates = [
             Local.with_ymd_and_hms(2024, 1, 3, 0, 0, 0).unwrap(),
             Local.with_ymd_and_hms(2024, 1, 10, 0, 0, 0).unwrap(),
             Local.with_ymd_and_hms(2024, 1, 12, 0, 0, 0).unwrap(),
@@ -951,15 +4117,37 @@ mod tests {
         ];
 
         // Iterate over the expected dates, checking each one
-        let mut idx = 0;
-        for current_date in cron.clone().iter_from(start_date).take(5) {
+        for (idx, current_date) in cron.clone().iter_from(start_date).take(5).enumerate() {
             assert_eq!(expected_dates[idx], current_date);
-            idx = idx + 1;
         }
 
         Ok(())
     }
 
+    #[test]
+    fn test_sparse_date_search_skips_non_matching_months() -> Result<(), CronError> {
+        // Feb 29 on a Monday only comes around every few decades
+        let cron = Cron::new("0 0 29 2 MON").with_dom_and_dow().parse()?;
+        let start = Utc.with_ymd_and_hms(2024, 3, 1, 0, 0, 0).unwrap();
+        let found: Vec<_> = cron.iter_from(start).take(3).collect();
+        assert_eq!(
+            found,
+            vec![
+                Utc.with_ymd_and_hms(2044, 2, 29, 0, 0, 0).unwrap(),
+                Utc.with_ymd_and_hms(2072, 2, 29, 0, 0, 0).unwrap(),
+                Utc.with_ymd_and_hms(2112, 2, 29, 0, 0, 0).unwrap(),
+            ]
+        );
+
+        // A day that never exists in the only allowed month runs into the search limit
+        let cron = Cron::new("0 0 30 2 *").parse()?;
+        assert!(matches!(
+            cron.find_next_occurrence(&start, false),
+            Err(CronError::TimeSearchLimitExceeded)
+        ));
+        Ok(())
+    }
+
     #[test]
     fn test_cron_expression_31dec_fri() -> Result<(), CronError> {
         // Parse the cron expression
@@ -972,7 +4160,7 @@ mod tests {
         let start_date = Local.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
 
         // Define the expected matching dates
-        let expected_dates = vec![
+        let expected_dates = [
             Local.with_ymd_and_hms(2027, 12, 31, 0, 0, 0).unwrap(),
             Local.with_ymd_and_hms(2032, 12, 31, 0, 0, 0).unwrap(),
             Local.with_ymd_and_hms(2038, 12, 31, 0, 0, 0).unwrap(),
@@ -981,10 +4169,8 @@ mod tests {
         ];
 
         // Iterate over the expected dates, checking each one
-        let mut idx = 0;
-        for current_date in cron.clone().iter_from(start_date).take(5) {
+        for (idx, current_date) in cron.clone().iter_from(start_date).take(5).enumerate() {
             assert_eq!(expected_dates[idx], current_date);
-            idx = idx + 1;
         }
 
         Ok(())
@@ -1003,6 +4189,8 @@ mod tests {
             "1- 59 * * * *",
             "0 0 0 5L * *",
             "0 0 0 5#L * *",
+            "0 0 * * 5L#2",
+            "0 0 * * 5#2L",
         ];
         for expr in invalid_expressions {
             assert!(Cron::new(expr).with_seconds_optional().parse().is_err());
@@ -1136,7 +4324,7 @@ mod tests {
 
     #[test]
     fn test_cron_iterator_non_standard_intervals_with_offset() -> Result<(), CronError> {
-        let cron = Cron::new("7/29 2/13 * * *").parse()?;
+        let cron = Cron::new("7-59/29 2-23/13 * * *").parse()?;
         let start_time = Local.with_ymd_and_hms(2023, 1, 1, 0, 0, 0).unwrap();
         let mut iterator = cron.iter_after(start_time);
 
@@ -1209,7 +4397,7 @@ mod tests {
         let start_date = Local.with_ymd_and_hms(2023, 12, 24, 0, 0, 0).unwrap();
 
         // Define the expected matching dates
-        let expected_dates = vec![
+        let expected_dates = [
             Local.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap(),
             Local.with_ymd_and_hms(2024, 2, 5, 0, 0, 0).unwrap(),
             Local.with_ymd_and_hms(2024, 3, 4, 0, 0, 0).unwrap(),
@@ -1241,7 +4429,7 @@ mod tests {
         let start_date = Local.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
 
         // Define the expected matching dates
-        let expected_dates = vec![
+        let expected_dates = [
             Local.with_ymd_and_hms(2024, 3, 29, 0, 0, 0).unwrap(),
             Local.with_ymd_and_hms(2030, 3, 29, 0, 0, 0).unwrap(),
             Local.with_ymd_and_hms(2036, 2, 29, 0, 0, 0).unwrap(),
@@ -1272,7 +4460,7 @@ mod tests {
         let start_date = Local.with_ymd_and_hms(2024, 10, 1, 0, 0, 0).unwrap();
 
         // Define the expected matching dates
-        let expected_dates = vec![
+        let expected_dates = [
             Local.with_ymd_and_hms(2024, 10, 13, 0, 0, 0).unwrap(),
             Local.with_ymd_and_hms(2024, 11, 10, 0, 0, 0).unwrap(),
             Local.with_ymd_and_hms(2024, 12, 8, 0, 0, 0).unwrap(),
@@ -1292,6 +4480,93 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_every_interval_nickname() -> Result<(), CronError> {
+        let cron = Cron::new("@every 15m").parse()?;
+        let start_time = Local.with_ymd_and_hms(2023, 1, 1, 10, 7, 13).unwrap();
+        let expected_dates = vec![
+            Local.with_ymd_and_hms(2023, 1, 1, 10, 15, 0).unwrap(),
+            Local.with_ymd_and_hms(2023, 1, 1, 10, 30, 0).unwrap(),
+            Local.with_ymd_and_hms(2023, 1, 1, 10, 45, 0).unwrap(),
+            Local.with_ymd_and_hms(2023, 1, 1, 11, 0, 0).unwrap(),
+        ];
+        let runs: Vec<_> = cron.iter_after(start_time).take(4).collect();
+        assert_eq!(runs, expected_dates);
+
+        let cron = Cron::new("@every 20s").with_seconds_optional().parse()?;
+        let runs: Vec<_> = cron.iter_after(start_time).take(3).collect();
+        assert_eq!(
+            runs[0],
+            Local.with_ymd_and_hms(2023, 1, 1, 10, 7, 20).unwrap()
+        );
+        assert_eq!(
+            runs[2],
+            Local.with_ymd_and_hms(2023, 1, 1, 10, 8, 0).unwrap()
+        );
+
+        assert!(Cron::new("@every 20s").parse().is_err());
+        assert!(Cron::new("@every 90m").parse().is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_hashed_pattern_is_stable_per_seed() -> Result<(), CronError> {
+        use std::collections::hash_map::DefaultHasher;
+        use std::hash::{Hash, Hasher};
+
+        let seed_for = |job: &str| {
+            let mut hasher = DefaultHasher::new();
+            job.hash(&mut hasher);
+            hasher.finish()
+        };
+        let start_time = Local.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        let next_for = |seed: u64| -> Result<_, CronError> {
+            Cron::new("H H * * *")
+                .with_hash_seed(seed)
+                .parse()?
+                .find_next_occurrence(&start_time, false)
+        };
+
+        // The same job identity always resolves to the same time
+        assert_eq!(
+            next_for(seed_for("backup-job"))?,
+            next_for(seed_for("backup-job"))?
+        );
+
+        // Different job identities are spread out
+        let mut distinct = std::collections::HashSet::new();
+        for job in ["backup-job", "cleanup-job", "report-job", "sync-job"] {
+            distinct.insert(next_for(seed_for(job))?);
+        }
+        assert!(distinct.len() > 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_wrapping_hour_range() -> Result<(), CronError> {
+        let cron = Cron::new("0 0 22-2 * * *")
+            .with_seconds_required()
+            .parse()?;
+        assert!(cron.is_time_matching(&Local.with_ymd_and_hms(2024, 1, 1, 23, 0, 0).unwrap())?);
+        assert!(cron.is_time_matching(&Local.with_ymd_and_hms(2024, 1, 1, 1, 0, 0).unwrap())?);
+        assert!(!cron.is_time_matching(&Local.with_ymd_and_hms(2024, 1, 1, 3, 0, 0).unwrap())?);
+
+        let cron = Cron::new("0 0 22-2/2 * * *")
+            .with_seconds_required()
+            .parse()?;
+        let start_time = Local.with_ymd_and_hms(2024, 1, 1, 12, 0, 0).unwrap();
+        let hours: Vec<u32> = cron
+            .iter_after(start_time)
+            .take(3)
+            .map(|t| t.hour())
+            .collect();
+        assert_eq!(hours, [22, 0, 2]);
+
+        Ok(())
+    }
+
     #[test]
     fn test_specific_and_wildcard_entries() -> Result<(), CronError> {
         let cron = Cron::new("15 */2 * 3,5 FRI").parse()?;
@@ -1385,6 +4660,17 @@ mod tests {
         }
     }
 
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_hashed_serde_tokens() {
+        // Hashed values serialize as resolved, so deserializing without the seed keeps the schedule
+        let cron = Cron::new("H H * * *")
+            .with_hash_seed(42)
+            .parse()
+            .expect("should be valid pattern");
+        assert_tokens(&cron, &[Token::Str("31 18 * * *")]);
+    }
+
     #[cfg(feature = "serde")]
     #[test]
     fn test_invalid_serde_tokens() {
@@ -1,31 +1,169 @@
 use crate::component::{
-    CronComponent, ALL_BIT, CLOSEST_WEEKDAY_BIT, LAST_BIT, NONE_BIT, NTH_1ST_BIT, NTH_2ND_BIT,
-    NTH_3RD_BIT, NTH_4TH_BIT, NTH_5TH_BIT, NTH_ALL,
+    CronComponent, Direction, ALL_BIT, CLOSEST_WEEKDAY_BIT, LAST_BIT, NONE_BIT, NTH_1ST_BIT,
+    NTH_2ND_BIT, NTH_3RD_BIT, NTH_4TH_BIT, NTH_5TH_BIT, NTH_ALL,
+};
+use crate::errors::{CronError, CronField};
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
 };
-use crate::errors::CronError;
 use chrono::{Datelike, Duration, NaiveDate, Weekday};
 
-// This struct is used for representing and validating cron pattern strings.
-// It supports parsing cron patterns with optional seconds field and provides functionality to check pattern matching against specific datetime.
+/// How an occurrence is handled when its wall-clock time is skipped by a daylight saving
+/// transition, like 02:30 when clocks move forward from 02:00 to 03:00.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum DstGapPolicy {
+    /// Fire at the first valid time after the gap. This is the default.
+    #[default]
+    SnapForward,
+    /// Do not fire at all.
+    Skip,
+}
+
+/// How an occurrence is handled when its wall-clock time happens twice due to a daylight saving
+/// transition, like 01:30 when clocks move back from 02:00 to 01:00.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum DstOverlapPolicy {
+    /// Fire at the first of the two instants. This is the default, and gives a single occurrence
+    /// per wall-clock time even for patterns firing every minute or second.
+    #[default]
+    FireFirst,
+    /// Fire at both instants.
+    FireBoth,
+    /// Fire at the last of the two instants.
+    FireLast,
+}
+
+/// The language of weekday and month names accepted in a pattern, in addition to English.
+///
+/// English names like `MON` and `JAN` are always accepted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Locale {
+    /// English names only. This is the default.
+    #[default]
+    English,
+    /// Swedish abbreviations, like `MÅN-FRE` and `MAJ`.
+    Swedish,
+    /// German abbreviations, like `MO-FR` and `MÄR`.
+    German,
+}
+
+impl Locale {
+    // The localized names differing from the English ones, mapped to their English counterparts
+    fn names(self) -> &'static [(&'static str, &'static str)] {
+        match self {
+            Locale::English => &[],
+            Locale::Swedish => &[
+                ("mån", "mon"),
+                ("tis", "tue"),
+                ("ons", "wed"),
+                ("tor", "thu"),
+                ("fre", "fri"),
+                ("lör", "sat"),
+                ("sön", "sun"),
+                ("maj", "may"),
+                ("okt", "oct"),
+            ],
+            Locale::German => &[
+                ("mo", "mon"),
+                ("di", "tue"),
+                ("mi", "wed"),
+                ("do", "thu"),
+                ("fr", "fri"),
+                ("sa", "sat"),
+                ("so", "sun"),
+                ("mär", "mar"),
+                ("mai", "may"),
+                ("okt", "oct"),
+                ("dez", "dec"),
+            ],
+        }
+    }
+}
+
+// Everything deciding when a parsed pattern fires, used to compare and hash patterns by their
+// canonical fields rather than by how they were written
+pub(crate) type ScheduleKey = (String, bool, DstGapPolicy, DstOverlapPolicy);
+
+/// Reports which rules of the day-of-month and day-of-week fields made a day match.
+///
+/// Only rules of the fields deciding the match are reported, so a wildcard day of week is not
+/// reported when the day of month is restricted, and vice versa.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct MatchReport {
+    /// The day of month was listed explicitly, or the field is a wildcard.
+    pub day_of_month: bool,
+    /// The day is the last day of the month, through `L`.
+    pub last_day_of_month: bool,
+    /// The day is an offset from the last day of the month, through `L-n`.
+    pub last_day_offset: bool,
+    /// The day is the weekday closest to a listed day, through `W`.
+    pub closest_weekday: bool,
+    /// The day of week was listed explicitly, or the field is a wildcard.
+    pub day_of_week: bool,
+    /// The day is the nth weekday of the month, through `#`.
+    pub nth_weekday: bool,
+    /// The day is the last of its weekday in the month, through `L`.
+    pub last_weekday: bool,
+}
+
+impl MatchReport {
+    fn any(&self) -> bool {
+        *self != MatchReport::default()
+    }
+
+    fn union(self, other: MatchReport) -> MatchReport {
+        MatchReport {
+            day_of_month: self.day_of_month || other.day_of_month,
+            last_day_of_month: self.last_day_of_month || other.last_day_of_month,
+            last_day_offset: self.last_day_offset || other.last_day_offset,
+            closest_weekday: self.closest_weekday || other.closest_weekday,
+            day_of_week: self.day_of_week || other.day_of_week,
+            nth_weekday: self.nth_weekday || other.nth_weekday,
+            last_weekday: self.last_weekday || other.last_weekday,
+        }
+    }
+}
+
+/// A parsed cron pattern, as read through [`Cron::pattern`](crate::Cron::pattern).
+///
+/// The parsed fields and the options the pattern was parsed with are read-only, so a pattern can
+/// not be changed into an inconsistent state after parsing. Use the `with_*` methods of
+/// [`Cron`](crate::Cron) to set options before parsing.
 #[derive(Debug, Clone)]
 pub struct CronPattern {
     pattern: String, // The original pattern
     //
-    pub seconds: CronComponent,      // -
-    pub minutes: CronComponent,      // --
-    pub hours: CronComponent,        // --- Each individual part of the cron expression
-    pub days: CronComponent,         // --- represented by a bitmask, min and max value
-    pub months: CronComponent,       // --
-    pub days_of_week: CronComponent, // -
+    pub(crate) seconds: CronComponent,      // -
+    pub(crate) minutes: CronComponent,      // --
+    pub(crate) hours: CronComponent,        // --- Each individual part of the cron expression
+    pub(crate) days: CronComponent,         // --- represented by a bitmask, min and max value
+    pub(crate) months: CronComponent,       // --
+    pub(crate) days_of_week: CronComponent, // -
 
     star_dom: bool,
     star_dow: bool,
+    q_dom: bool, // The day-of-month field was "?", leaving the day to the day-of-week field
+    q_dow: bool, // The day-of-week field was "?", leaving the day to the day-of-month field
+
+    tokens: Vec<String>,   // The raw text of each of the six fields, as parsed
+    warnings: Vec<String>, // Adjustments made while parsing leniently
 
     // Options
-    pub dom_and_dow: bool, // Setting to alter how dom_and_dow is combined
-    pub with_seconds_optional: bool, // Setting to alter if seconds (6-part patterns) are allowed or not
-    pub with_seconds_required: bool, // Setting to alter if seconds (6-part patterns) are required or not
-    pub with_alternative_weekdays: bool, // Setting to alter if weekdays are offset by one or not
+    pub(crate) dom_and_dow: bool, // Setting to alter how dom_and_dow is combined
+    pub(crate) with_seconds_optional: bool, // Setting to alter if seconds (6-part patterns) are allowed or not
+    pub(crate) with_seconds_required: bool, // Setting to alter if seconds (6-part patterns) are required or not
+    pub(crate) with_alternative_weekdays: bool, // Setting to alter if weekdays are offset by one or not
+    pub(crate) with_quartz: bool, // Setting to accept Quartz expressions, with an optional wildcard year field
+    pub(crate) hash_seed: u64,    // Seed used to resolve hashed (H) values
+    pub(crate) dst_gap: DstGapPolicy, // Setting to alter how times skipped by DST are handled
+    pub(crate) dst_overlap: DstOverlapPolicy, // Setting to alter how times repeated by DST are handled
+    pub(crate) max_fires_per_day: Option<u64>, // Setting to reject patterns firing more often than this per day
+    pub(crate) lenient: bool, // Setting to clamp out-of-range values instead of rejecting them
+    pub(crate) reject_impossible: bool, // Setting to reject day and month combinations that never exist
+    pub(crate) locale: Locale, // Setting to accept weekday and month names of another language
 
     // Status
     is_parsed: bool,
@@ -33,23 +171,35 @@ pub struct CronPattern {
 
 // Implementation block for CronPattern struct, providing methods for creating and parsing cron pattern strings.
 impl CronPattern {
-    pub fn new(pattern: &str) -> Self {
+    pub(crate) fn new(pattern: &str) -> Self {
         Self {
             pattern: pattern.to_string(),
-            seconds: CronComponent::new(0, 59, NONE_BIT, 0),
-            minutes: CronComponent::new(0, 59, NONE_BIT, 0),
-            hours: CronComponent::new(0, 23, NONE_BIT, 0),
-            days: CronComponent::new(1, 31, LAST_BIT | CLOSEST_WEEKDAY_BIT, 0), // Special bit LAST_BIT is available
-            months: CronComponent::new(1, 12, NONE_BIT, 0),
-            days_of_week: CronComponent::new(0, 7, LAST_BIT | NTH_ALL, 0), // Actually 0-7 in pattern, 7 is converted to 0 in POSIX mode
+            seconds: CronComponent::new(0, 59, NONE_BIT, 0).with_wrapping(),
+            minutes: CronComponent::new(0, 59, NONE_BIT, 0).with_wrapping(),
+            hours: CronComponent::new(0, 23, NONE_BIT, 0).with_wrapping(),
+            days: CronComponent::new(1, 31, LAST_BIT | CLOSEST_WEEKDAY_BIT, 0), // Special bit LAST_BIT is available, ranges can not wrap as month lengths vary
+            months: CronComponent::new(1, 12, NONE_BIT, 0).with_wrapping(),
+            days_of_week: CronComponent::new(0, 7, LAST_BIT | NTH_ALL, 0).with_wrapping_after(6), // Actually 0-7 in pattern, 7 is converted to 0 in POSIX mode
             star_dom: false,
             star_dow: false,
+            q_dom: false,
+            q_dow: false,
+            tokens: Vec::new(),
+            warnings: Vec::new(),
 
             // Options
             dom_and_dow: false,
             with_seconds_optional: false,
             with_seconds_required: false,
             with_alternative_weekdays: false,
+            with_quartz: false,
+            hash_seed: 0,
+            dst_gap: DstGapPolicy::default(),
+            dst_overlap: DstOverlapPolicy::default(),
+            max_fires_per_day: None,
+            lenient: false,
+            reject_impossible: false,
+            locale: Locale::default(),
 
             // Status
             is_parsed: false,
@@ -58,14 +208,37 @@ impl CronPattern {
 
     // Parses the cron pattern string into its respective fields.
     // Handles optional seconds field, named shortcuts, and determines if 'L' flag is used for last day of the month.
-    pub fn parse(&mut self) -> Result<CronPattern, CronError> {
+    pub(crate) fn parse(&mut self) -> Result<CronPattern, CronError> {
         if self.pattern.trim().is_empty() {
             return Err(CronError::EmptyPattern);
         }
 
-        // Replace any '?' with '*' in the cron pattern
+        // Keep the pattern as written, so that errors point at the text the user gave rather than
+        // at the rewritten pattern
+        let original = self.pattern.clone();
+        let original_fields: Vec<(&str, usize)> = original
+            .split_whitespace()
+            .map(|part| (part, part.as_ptr() as usize - original.as_ptr() as usize))
+            .collect();
+
+        // Remember which fields were given as '?' before replacing any '?' with '*' in the cron pattern.
+        // Nicknames and aliases never contain '?', so the fields line up with the parsed parts
+        let question_marks: Vec<bool> = self
+            .pattern
+            .split_whitespace()
+            .map(|part| part == "?")
+            .collect();
         self.pattern = self.pattern.replace('?', "*");
 
+        // Handle @every <duration> intervals
+        if self.pattern.trim().to_lowercase().starts_with("@every") {
+            self.pattern = Self::handle_every(
+                &self.pattern,
+                self.with_seconds_optional,
+                self.with_seconds_required,
+            )?;
+        }
+
         // Handle @nicknames
         if self.pattern.contains('@') {
             self.pattern = Self::handle_nicknames(&self.pattern, self.with_seconds_required)
@@ -73,6 +246,9 @@ impl CronPattern {
                 .to_string();
         }
 
+        // Translate localized aliases to English before handling them (MÅN... to MON...)
+        self.pattern = Self::replace_locale_names(&self.pattern, self.locale);
+
         // Handle day-of-week and month aliases (MON... and JAN...)
         self.pattern = Self::replace_alpha_weekdays(&self.pattern, self.with_alternative_weekdays)
             .trim()
@@ -81,6 +257,28 @@ impl CronPattern {
 
         // Check that the pattern contains 5 or 6 parts
         let mut parts: Vec<&str> = self.pattern.split_whitespace().collect();
+
+        // Aliases are replaced within their field, so the fields as written line up with the
+        // parts unless a nickname was expanded. Errors then point at the whole pattern
+        let mut written = if original_fields.len() == parts.len() {
+            original_fields
+        } else {
+            let start = original.len() - original.trim_start().len();
+            vec![(original.trim(), start); parts.len()]
+        };
+
+        // Quartz expressions may end with a year field, years are not supported beyond a wildcard
+        let with_year = self.with_quartz && parts.len() == 7;
+        if with_year {
+            if parts[6] != "*" {
+                return Err(CronError::InvalidPattern(String::from(
+                    "Quartz year field is not supported, only * can be given.",
+                )));
+            }
+            parts.pop();
+            written.pop();
+        }
+
         if parts.len() < 5 || parts.len() > 6 {
             return Err(CronError::InvalidPattern(String::from("Pattern must consist of five or six fields (minute, hour, day, month, day of week, and optional second).")));
         }
@@ -100,23 +298,118 @@ impl CronPattern {
         }
 
         // Default seconds to "0" if omitted
-        if parts.len() == 5 {
+        let seconds_omitted = parts.len() == 5;
+        if seconds_omitted {
             parts.insert(0, "0"); // prepend "0" if the seconds part is missing
+            written.insert(0, ("0", 0)); // never fails to parse
 
             // Error it there is an extra part and seconds are not allowed
         }
 
+        // Resolve hashed (H) values into concrete numbers
+        let hashed = parts.iter().any(|part| part.contains('h'));
+        let mut resolved = parts
+            .iter()
+            .enumerate()
+            .map(|(index, part)| self.resolve_hashed(index, part))
+            .collect::<Result<Vec<String>, CronError>>()?;
+
+        // Keep the resolved values in the pattern, so that it displays and serializes as the same
+        // schedule when parsed again without the seed
+        if hashed {
+            let mut fields: Vec<&str> = resolved
+                .iter()
+                .skip(usize::from(seconds_omitted))
+                .map(String::as_str)
+                .collect();
+            if with_year {
+                fields.push("*");
+            }
+            self.pattern = fields.join(" ");
+        }
+
+        // A leading + in the day-of-week field combines it with the day of month using AND, like
+        // with_dom_and_dow does for the whole pattern. Anywhere else, + is not allowed
+        if let Some(days_of_week) = resolved[5].strip_prefix('+') {
+            resolved[5] = days_of_week.to_string();
+            if let Some(days_of_week) = written[5].0.strip_prefix('+') {
+                written[5] = (days_of_week, written[5].1 + 1);
+            }
+            self.dom_and_dow = true;
+        }
+        if resolved.iter().any(|part| part.contains('+')) {
+            return Err(CronError::IllegalCharacters(String::from(
+                "+ (AND modifier) is only valid at the start of the day-of-week field.",
+            )));
+        }
+
+        // Clamp out-of-range values into their field when parsing leniently
+        self.warnings.clear();
+        if self.lenient {
+            let fields = [
+                (CronField::Second, self.seconds.input_range()),
+                (CronField::Minute, self.minutes.input_range()),
+                (CronField::Hour, self.hours.input_range()),
+                (CronField::DayOfMonth, self.days.input_range()),
+                (CronField::Month, self.months.input_range()),
+                (CronField::DayOfWeek, self.days_of_week.input_range()),
+            ];
+            for ((field, range), part) in fields.iter().zip(resolved.iter_mut()) {
+                *part = clamp_field(part, *field, *range, &mut self.warnings);
+            }
+        }
+        let parts: Vec<&str> = resolved.iter().map(String::as_str).collect();
+
+        self.tokens = resolved.clone();
+
         // Handle star-dom and star-dow
         self.star_dom = parts[3].trim() == "*";
         self.star_dow = parts[5].trim() == "*";
 
+        // Handle question marks in the day fields, the seconds field is missing from five-part patterns
+        let skipped = usize::from(question_marks.len() == 5);
+        let question_mark = |index: usize| {
+            question_marks
+                .get(index - skipped)
+                .copied()
+                .unwrap_or(false)
+        };
+        self.q_dom = question_mark(3);
+        self.q_dow = question_mark(5);
+
+        // W is a common mistake in the day of week field, point to the field where it belongs
+        if parts[5].contains(['W', 'w']) {
+            return Err(CronError::InvalidPattern(String::from(
+                "W (nearest weekday) is only valid in the day-of-month field.",
+            )));
+        }
+
+        // Quartz steps from a single start value, like 0/5, up to the end of the field
+        if self.with_quartz {
+            for component in [
+                &mut self.seconds,
+                &mut self.minutes,
+                &mut self.hours,
+                &mut self.days,
+                &mut self.months,
+                &mut self.days_of_week,
+            ] {
+                *component = core::mem::take(component).with_single_start_steps();
+            }
+        }
+
         // Parse the individual components
-        self.seconds.parse(parts[0])?;
-        self.minutes.parse(parts[1])?;
-        self.hours.parse(parts[2])?;
-        self.days.parse(parts[3])?;
-        self.months.parse(parts[4])?;
-        self.days_of_week.parse(parts[5])?;
+        Self::parse_field(&mut self.seconds, CronField::Second, parts[0], written[0])?;
+        Self::parse_field(&mut self.minutes, CronField::Minute, parts[1], written[1])?;
+        Self::parse_field(&mut self.hours, CronField::Hour, parts[2], written[2])?;
+        Self::parse_field(&mut self.days, CronField::DayOfMonth, parts[3], written[3])?;
+        Self::parse_field(&mut self.months, CronField::Month, parts[4], written[4])?;
+        Self::parse_field(
+            &mut self.days_of_week,
+            CronField::DayOfWeek,
+            parts[5],
+            written[5],
+        )?;
 
         // Handle conversion of 7 to 0 for day_of_week if necessary
         // this has to be done last because range could be 6-7 (sat-sun)
@@ -128,6 +421,7 @@ impl CronPattern {
                 NTH_3RD_BIT,
                 NTH_4TH_BIT,
                 NTH_5TH_BIT,
+                LAST_BIT,
             ] {
                 if self.days_of_week.is_bit_set(7, nth_bit)? {
                     self.days_of_week.unset_bit(7, nth_bit)?;
@@ -136,46 +430,114 @@ impl CronPattern {
             }
         }
 
+        // Reject patterns firing more often than allowed
+        if let Some(max_fires_per_day) = self.max_fires_per_day {
+            let fires_per_day = self.occurrences_per_day_estimate()?;
+            if fires_per_day > max_fires_per_day {
+                return Err(CronError::InvalidPattern(format!(
+                    "Pattern fires {} times per day, exceeding the limit of {}.",
+                    fires_per_day, max_fires_per_day
+                )));
+            }
+        }
+
+        // Reject patterns that can never fire, like the 30th of February
+        if self.reject_impossible && !self.days_exist_in_months() {
+            return Err(CronError::InvalidPattern(String::from(
+                "Pattern can never match, none of the days of month exist in the selected months.",
+            )));
+        }
+
         // Success!
         self.is_parsed = true;
         Ok(self.clone())
     }
 
-    // Validates that the cron pattern only contains legal characters for each field.
-    // - ? is replaced with * before parsing, so it does not need to be included
-    pub fn throw_at_illegal_characters(&self, parts: &[&str]) -> Result<(), CronError> {
-        let base_allowed_characters = [
-            '*', '/', '0', '1', '2', '3', '4', '5', '6', '7', '8', '9', ',', '-',
-        ];
-        let day_of_week_additional_characters = ['#', 'W'];
-        let day_of_month_additional_characters = ['L'];
-
-        for (i, part) in parts.iter().enumerate() {
-            // Decide which set of allowed characters to use
-            let allowed = match i {
-                5 => [
-                    base_allowed_characters.as_ref(),
-                    day_of_week_additional_characters.as_ref(),
-                ]
-                .concat(),
-                3 => [
-                    base_allowed_characters.as_ref(),
-                    day_of_month_additional_characters.as_ref(),
-                ]
-                .concat(),
-                _ => base_allowed_characters.to_vec(),
+    // Parses a single field into its component. On failure, the offending entry of the field is
+    // located by parsing each entry on its own, so that the error can point at the exact token.
+    // The error reports the entry as written, given with its offset in the original pattern.
+    fn parse_field(
+        component: &mut CronComponent,
+        field: CronField,
+        text: &str,
+        (written, offset): (&str, usize),
+    ) -> Result<(), CronError> {
+        let pristine = component.clone();
+        component.parse(text).map_err(|error| {
+            let reason = match error {
+                CronError::ComponentError(reason) => reason,
+                other => other.to_string(),
             };
-
-            for ch in part.chars() {
-                if !allowed.contains(&ch) {
-                    return Err(CronError::IllegalCharacters(String::from(
-                        "CronPattern contains illegal characters.",
-                    )));
+            let failing = text.split(',').position(|entry| {
+                let entry = entry.trim();
+                !entry.is_empty() && pristine.clone().parse(entry).is_err()
+            });
+            let mut entry_offset = offset;
+            let written_entries: Vec<(&str, usize)> = written
+                .split(',')
+                .map(|entry| {
+                    let entry_start = entry_offset + (entry.len() - entry.trim_start().len());
+                    entry_offset += entry.len() + 1;
+                    (entry.trim(), entry_start)
+                })
+                .collect();
+            let (part, offset) = match failing {
+                Some(index) if written_entries.len() == text.split(',').count() => {
+                    written_entries[index]
                 }
+                _ => (written, offset),
+            };
+            CronError::FieldError {
+                field,
+                part: part.to_string(),
+                offset,
+                reason,
             }
+        })
+    }
+
+    // Estimates how many times the pattern fires on a day it matches, as the product of the
+    // number of matching hours, minutes and seconds
+    pub(crate) fn occurrences_per_day_estimate(&self) -> Result<u64, CronError> {
+        let mut estimate = 1;
+        for component in [&self.hours, &self.minutes, &self.seconds] {
+            estimate *= u64::from(component.count_set_values(ALL_BIT));
         }
+        Ok(estimate)
+    }
 
-        Ok(())
+    // Reports list entries that overlap with, or are fully covered by, an earlier entry of the same
+    // field, like "minute: 3-7 overlaps 1-5". Purely informational, matching is not affected.
+    pub(crate) fn redundancies(&self) -> Vec<String> {
+        let fields = [
+            (CronField::Second, &self.seconds),
+            (CronField::Minute, &self.minutes),
+            (CronField::Hour, &self.hours),
+            (CronField::DayOfMonth, &self.days),
+            (CronField::Month, &self.months),
+            (CronField::DayOfWeek, &self.days_of_week),
+        ];
+        let mut redundancies = Vec::new();
+        for ((field, component), token) in fields.iter().zip(&self.tokens) {
+            let mut seen: Vec<(&str, [u64; 8])> = Vec::new();
+            for entry in token.split(',').map(str::trim).filter(|e| !e.is_empty()) {
+                let Ok(bits) = component.entry_bits(entry) else {
+                    continue;
+                };
+                for (previous, previous_bits) in &seen {
+                    let pairs = || bits.iter().zip(previous_bits.iter());
+                    if pairs().all(|(b, p)| b & !p == 0) {
+                        redundancies.push(format!("{field}: {entry} is covered by {previous}"));
+                        break;
+                    } else if pairs().any(|(b, p)| b & p != 0) {
+                        redundancies.push(format!("{field}: {entry} overlaps {previous}"));
+                        break;
+                    }
+                }
+                seen.push((entry, bits));
+            }
+        }
+        redundancies
     }
 
     // Converts named cron pattern shortcuts like '@daily' into their equivalent standard cron pattern.
@@ -201,6 +563,198 @@ impl CronPattern {
         }
     }
 
+    // Converts an '@every <duration>' interval like '@every 15m' into a stepped cron pattern.
+    // Only durations that divide evenly into a single field (seconds, minutes or hours) are
+    // accepted, as anything else would fire at an uneven cadence when the field wraps.
+    fn handle_every(
+        pattern: &str,
+        with_seconds_optional: bool,
+        with_seconds_required: bool,
+    ) -> Result<String, CronError> {
+        let duration = pattern.trim()[6..].trim();
+        if duration.is_empty() {
+            return Err(CronError::InvalidPattern(String::from(
+                "@every must be followed by a duration, like '@every 15m'.",
+            )));
+        }
+
+        // Sum up all <number><unit> pairs, e.g. "1h30m"
+        let mut total_seconds: u64 = 0;
+        let mut rest = duration;
+        while !rest.is_empty() {
+            let number_end = rest
+                .find(|c: char| !c.is_ascii_digit())
+                .unwrap_or(rest.len());
+            let unit_end = rest[number_end..]
+                .find(|c: char| c.is_ascii_digit())
+                .map_or(rest.len(), |pos| number_end + pos);
+            let number = rest[..number_end].parse::<u64>().map_err(|_| {
+                CronError::InvalidPattern(format!("Invalid duration '{}' in @every.", duration))
+            })?;
+            let multiplier = match rest[number_end..unit_end].trim().to_lowercase().as_str() {
+                "s" | "sec" | "secs" | "second" | "seconds" => 1,
+                "m" | "min" | "mins" | "minute" | "minutes" => 60,
+                "h" | "hr" | "hrs" | "hour" | "hours" => 3600,
+                "d" | "day" | "days" => 86400,
+                _ => {
+                    return Err(CronError::InvalidPattern(format!(
+                        "Invalid duration '{}' in @every, expected units s, m, h or d.",
+                        duration
+                    )))
+                }
+            };
+            total_seconds = number
+                .checked_mul(multiplier)
+                .and_then(|s| total_seconds.checked_add(s))
+                .ok_or_else(|| {
+                    CronError::InvalidPattern(format!("Duration '{}' is too large.", duration))
+                })?;
+            rest = rest[unit_end..].trim_start();
+        }
+
+        // Map the interval onto a single stepped field
+        let stepped = |step: u64| {
+            if step == 1 {
+                String::from("*")
+            } else {
+                format!("*/{}", step)
+            }
+        };
+        let base_pattern = match total_seconds {
+            0 => {
+                return Err(CronError::InvalidPattern(String::from(
+                    "@every duration must be greater than zero.",
+                )))
+            }
+            s if s < 60 && 60 % s == 0 => {
+                if !(with_seconds_optional || with_seconds_required) {
+                    return Err(CronError::InvalidPattern(format!(
+                        "@every {} requires seconds to be allowed by configuration.",
+                        duration
+                    )));
+                }
+                return Ok(format!("{} * * * * *", stepped(s)));
+            }
+            s if s % 60 == 0 && s < 3600 && 60 % (s / 60) == 0 => {
+                format!("{} * * * *", stepped(s / 60))
+            }
+            s if s % 3600 == 0 && s < 86400 && 24 % (s / 3600) == 0 => {
+                format!("0 {} * * *", stepped(s / 3600))
+            }
+            86400 => String::from("0 0 * * *"),
+            _ => {
+                return Err(CronError::InvalidPattern(format!(
+                    "@every {} is not an even interval of seconds, minutes or hours.",
+                    duration
+                )))
+            }
+        };
+
+        if with_seconds_required {
+            Ok(format!("0 {}", base_pattern))
+        } else {
+            Ok(base_pattern)
+        }
+    }
+
+    // Replaces Jenkins-style hashed values ('H' or 'H(a-b)') in a field with a stable
+    // pseudo-random value derived from the hash seed and the field index.
+    fn resolve_hashed(&self, index: usize, field: &str) -> Result<String, CronError> {
+        if !field.contains('h') {
+            return Ok(field.to_string());
+        }
+
+        // Day-of-month defaults to 1-28 so that the hashed day exists in every month
+        let (min, max, default_max) = match index {
+            0 | 1 => (0, 59, 59),
+            2 => (0, 23, 23),
+            3 => (1, 31, 28),
+            _ => {
+                return Err(CronError::InvalidPattern(String::from(
+                    "H is only allowed in the seconds, minutes, hours and day-of-month fields.",
+                )))
+            }
+        };
+
+        // SplitMix64, mixing in the field index so that each field gets its own value
+        let mut hash = self
+            .hash_seed
+            .wrapping_add((index as u64 + 1).wrapping_mul(0x9E37_79B9_7F4A_7C15));
+        hash = (hash ^ (hash >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        hash = (hash ^ (hash >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        hash ^= hash >> 31;
+
+        let resolved: Vec<String> = field
+            .split(',')
+            .map(|part| {
+                let (start, end) = if part == "h" {
+                    (min, default_max)
+                } else if let Some(range) = part
+                    .strip_prefix("h(")
+                    .and_then(|rest| rest.strip_suffix(')'))
+                {
+                    let bounds: Vec<&str> = range.split('-').collect();
+                    let parse_bound = |bound: &str| {
+                        bound.trim().parse::<u64>().map_err(|_| {
+                            CronError::InvalidPattern(format!("Invalid hashed range '{}'.", part))
+                        })
+                    };
+                    if bounds.len() != 2 {
+                        return Err(CronError::InvalidPattern(format!(
+                            "Invalid hashed range '{}'.",
+                            part
+                        )));
+                    }
+                    (parse_bound(bounds[0])?, parse_bound(bounds[1])?)
+                } else if part.contains('h') {
+                    return Err(CronError::InvalidPattern(format!(
+                        "Invalid hashed value '{}', expected H or H(a-b).",
+                        part
+                    )));
+                } else {
+                    return Ok(part.to_string());
+                };
+                if start > end || start < min || end > max {
+                    return Err(CronError::InvalidPattern(format!(
+                        "Hashed range '{}' is out of bounds ({}-{}).",
+                        part, min, max
+                    )));
+                }
+                Ok((start + hash % (end - start + 1)).to_string())
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(resolved.join(","))
+    }
+
+    // Translates the weekday and month names of a locale into the English names. Only whole
+    // words are translated, as short names like the German MO are part of the English MON
+    fn replace_locale_names(pattern: &str, locale: Locale) -> String {
+        let names = locale.names();
+        if names.is_empty() {
+            return pattern.to_string();
+        }
+
+        let lowercase = pattern.to_lowercase();
+        let mut replaced = String::with_capacity(lowercase.len());
+        let mut rest = lowercase.as_str();
+        while let Some(start) = rest.find(char::is_alphabetic) {
+            replaced.push_str(&rest[..start]);
+            rest = &rest[start..];
+            let end = rest
+                .find(|c: char| !c.is_alphabetic())
+                .unwrap_or(rest.len());
+            let word = &rest[..end];
+            match names.iter().find(|(name, _)| *name == word) {
+                Some((_, english)) => replaced.push_str(english),
+                None => replaced.push_str(word),
+            }
+            rest = &rest[end..];
+        }
+        replaced.push_str(rest);
+        replaced
+    }
+
     // Converts day-of-week nicknames into their equivalent standard cron pattern.
     fn replace_alpha_weekdays(pattern: &str, alternative_weekdays: bool) -> String {
         // Day-of-week nicknames to their numeric values.
@@ -279,13 +833,27 @@ impl CronPattern {
                     return current.day() == date.day();
                 }
             }
-            current += chrono::Duration::days(1);
+            current = match current.succ_opt() {
+                Some(next_day) => next_day,
+                None => break,
+            };
         }
         false
     }
 
     // This method checks if a given year, month, and day match the day part of the cron pattern.
-    pub fn day_match(&self, year: i32, month: u32, day: u32) -> Result<bool, CronError> {
+    pub(crate) fn day_match(&self, year: i32, month: u32, day: u32) -> Result<bool, CronError> {
+        Ok(self.day_match_report(year, month, day)?.is_some())
+    }
+
+    // Checks whether a day matches, reporting which rules of the day-of-month and day-of-week
+    // fields made it match. Returns None if the day does not match.
+    pub(crate) fn day_match_report(
+        &self,
+        year: i32,
+        month: u32,
+        day: u32,
+    ) -> Result<Option<MatchReport>, CronError> {
         // First, check if the day is within the valid range
         if day == 0 || day > 31 || month == 0 || month > 12 {
             return Err(CronError::InvalidDate);
@@ -295,20 +863,30 @@ impl CronPattern {
         let date =
             chrono::NaiveDate::from_ymd_opt(year, month, day).ok_or(CronError::InvalidDate)?;
 
-        let mut day_matches = self.days.is_bit_set(day as u8, ALL_BIT)?;
-        let mut dow_matches = false;
+        let mut dom = MatchReport {
+            day_of_month: self.days.is_bit_set(day as u8, ALL_BIT)?,
+            ..MatchReport::default()
+        };
+        let mut dow = MatchReport::default();
 
-        // If the 'L' flag is used, we need to check if the given day is the last day of the month
-        if !day_matches && self.days.is_feature_enabled(LAST_BIT) {
+        // The last day of the month is only needed for 'L' and offsets from it (L-n)
+        let uses_last = self.days.is_feature_enabled(LAST_BIT);
+        let uses_last_offsets = self.days.count_set_values(LAST_BIT) > 0;
+        if !dom.any() && (uses_last || uses_last_offsets) {
             let last_day = CronPattern::last_day_of_month(year, month)?;
-            if !day_matches && day == last_day {
-                day_matches = true;
-            }
+
+            // If the 'L' flag is used, we need to check if the given day is the last day of the month
+            dom.last_day_of_month = uses_last && day == last_day;
+
+            // Check offsets from the last day of the month (L-n), stored as LAST_BIT at position n
+            dom.last_day_offset = uses_last_offsets
+                && day < last_day
+                && self.days.is_bit_set((last_day - day) as u8, LAST_BIT)?;
         }
 
         // Make an extra check if any adjacent day is matching through the closest-weekday flag
-        if !day_matches && self.closest_weekday(year, month, day)? {
-            day_matches = true;
+        if !dom.any() {
+            dom.closest_weekday = self.closest_weekday(year, month, day)?;
         }
 
         // Check for nth weekday of the month flags
@@ -326,41 +904,55 @@ impl CronPattern {
                 .is_bit_set(date.weekday().num_days_from_sunday() as u8, nth_bit)?
                 && CronPattern::is_nth_weekday_of_month(date, nth, date.weekday())
             {
-                dow_matches = true;
+                dow.nth_weekday = true;
                 break;
             }
         }
 
         // If the 'L' flag is used for the day of the week, check if it's the last one of the month
-        if !dow_matches
+        if !dow.any()
             && self
                 .days_of_week
                 .is_bit_set(date.weekday().num_days_from_sunday() as u8, LAST_BIT)?
         {
-            let next_weekday = date + chrono::Duration::days(7);
-            if !dow_matches && next_weekday.month() != date.month() {
-                // If adding 7 days changes the month, then it is the last occurrence of the day of the week
-                dow_matches = true;
-            }
+            // If adding 7 days changes the month, then it is the last occurrence of the day of the week
+            let next_weekday = date.checked_add_signed(chrono::Duration::days(7));
+            dow.last_weekday = !matches!(next_weekday, Some(next) if next.month() == date.month());
         }
 
         // Check if the specific day of the week is set in the bitset
         // Note: In chrono, Sunday is 0, Monday is 1, and so on...
-        let day_of_week = date.weekday().num_days_from_sunday() as u8; // Adjust as necessary for your bitset
-        dow_matches = dow_matches || self.days_of_week.is_bit_set(day_of_week, ALL_BIT)?;
+        if !dow.any() {
+            let day_of_week = date.weekday().num_days_from_sunday() as u8;
+            dow.day_of_week = self.days_of_week.is_bit_set(day_of_week, ALL_BIT)?;
+        }
 
         // The day matches if it's set in the days bitset or the days of the week bitset
-        if (day_matches && self.star_dow) || (dow_matches && self.star_dom) {
-            Ok(true)
+        let (day_matches, dow_matches) = (dom.any(), dow.any());
+        let report = if self.q_dom != self.q_dow {
+            // A '?' leaves the day to the other field alone, whether combined by OR or AND
+            if self.q_dom {
+                dow_matches.then_some(dow)
+            } else {
+                day_matches.then_some(dom)
+            }
+        } else if (day_matches && self.star_dow) || (dow_matches && self.star_dom) {
+            // Only the restricted field decides, unless both are wildcards
+            match (self.star_dom, self.star_dow) {
+                (true, false) => Some(dow),
+                (false, true) => Some(dom),
+                _ => Some(dom.union(dow)),
+            }
         } else if !self.star_dom && !self.star_dow {
             if !self.dom_and_dow {
-                Ok(day_matches || dow_matches)
+                (day_matches || dow_matches).then(|| dom.union(dow))
             } else {
-                Ok(day_matches && dow_matches)
+                (day_matches && dow_matches).then(|| dom.union(dow))
             }
         } else {
-            Ok(false)
-        }
+            None
+        };
+        Ok(report)
     }
 
     // Helper function to find the last day of a given month
@@ -385,7 +977,42 @@ impl CronPattern {
         Ok(last_day_date.day())
     }
 
-    pub fn closest_weekday(&self, year: i32, month: u32, day: u32) -> Result<bool, CronError> {
+    // Returns the days of a month the pattern matches, when they follow from the day-of-month field
+    // alone. Returns None when the day of week takes part, or W makes a day depend on the weekday
+    pub(crate) fn weekday_independent_days(&self, year: i32, month: u32) -> Option<Vec<u32>> {
+        let weekdays_decide = !self.star_dow && !self.q_dow;
+        if weekdays_decide || self.days.count_set_values(CLOSEST_WEEKDAY_BIT) > 0 {
+            return None;
+        }
+        let last_day = CronPattern::last_day_of_month(year, month).ok()?;
+        let mut days: Vec<u32> = self
+            .days
+            .iter_matching(ALL_BIT, Direction::Ascending)
+            .map(u32::from)
+            .filter(|&day| day <= last_day)
+            .collect();
+        if self.days.is_feature_enabled(LAST_BIT) {
+            days.push(last_day);
+        }
+        // Offsets from the last day of the month (L-n) are stored as LAST_BIT at position n
+        days.extend(
+            self.days
+                .iter_matching(LAST_BIT, Direction::Ascending)
+                .map(u32::from)
+                .filter(|&offset| offset < last_day)
+                .map(|offset| last_day - offset),
+        );
+        days.sort_unstable();
+        days.dedup();
+        Some(days)
+    }
+
+    pub(crate) fn closest_weekday(
+        &self,
+        year: i32,
+        month: u32,
+        day: u32,
+    ) -> Result<bool, CronError> {
         let candidate_date =
             NaiveDate::from_ymd_opt(year, month, day).ok_or(CronError::InvalidDate)?;
         let weekday = candidate_date.weekday();
@@ -398,17 +1025,24 @@ impl CronPattern {
             }
 
             // Check the previous and next days if the current day is a weekday
-            let previous_day = candidate_date - Duration::days(1);
-            let next_day = candidate_date + Duration::days(1);
-
-            let check_previous = previous_day.weekday() == Weekday::Sun
-                && self
-                    .days
-                    .is_bit_set(previous_day.day() as u8, CLOSEST_WEEKDAY_BIT)?;
-            let check_next = next_day.weekday() == Weekday::Sat
-                && self
-                    .days
-                    .is_bit_set(next_day.day() as u8, CLOSEST_WEEKDAY_BIT)?;
+            let check_previous = match candidate_date.pred_opt() {
+                Some(previous_day) => {
+                    previous_day.weekday() == Weekday::Sun
+                        && self
+                            .days
+                            .is_bit_set(previous_day.day() as u8, CLOSEST_WEEKDAY_BIT)?
+                }
+                None => false,
+            };
+            let check_next = match candidate_date.succ_opt() {
+                Some(next_day) => {
+                    next_day.weekday() == Weekday::Sat
+                        && self
+                            .days
+                            .is_bit_set(next_day.day() as u8, CLOSEST_WEEKDAY_BIT)?
+                }
+                None => false,
+            };
             if check_previous || check_next {
                 return Ok(true);
             }
@@ -418,7 +1052,7 @@ impl CronPattern {
     }
 
     // Checks if a given month matches the month part of the cron pattern.
-    pub fn month_match(&self, month: u32) -> Result<bool, CronError> {
+    pub(crate) fn month_match(&self, month: u32) -> Result<bool, CronError> {
         if month == 0 || month > 12 {
             return Err(CronError::InvalidDate);
         }
@@ -426,7 +1060,7 @@ impl CronPattern {
     }
 
     // Checks if a given hour matches the hour part of the cron pattern.
-    pub fn hour_match(&self, hour: u32) -> Result<bool, CronError> {
+    pub(crate) fn hour_match(&self, hour: u32) -> Result<bool, CronError> {
         if hour > 23 {
             return Err(CronError::InvalidTime);
         }
@@ -434,7 +1068,7 @@ impl CronPattern {
     }
 
     // Checks if a given minute matches the minute part of the cron pattern.
-    pub fn minute_match(&self, minute: u32) -> Result<bool, CronError> {
+    pub(crate) fn minute_match(&self, minute: u32) -> Result<bool, CronError> {
         if minute > 59 {
             return Err(CronError::InvalidTime);
         }
@@ -442,7 +1076,7 @@ impl CronPattern {
     }
 
     // Checks if a given second matches the second part of the cron pattern.
-    pub fn second_match(&self, second: u32) -> Result<bool, CronError> {
+    pub(crate) fn second_match(&self, second: u32) -> Result<bool, CronError> {
         if second > 59 {
             return Err(CronError::InvalidTime);
         }
@@ -450,7 +1084,7 @@ impl CronPattern {
     }
 
     // Finds the next hour that matches the hour part of the cron pattern.
-    pub fn next_hour_match(&self, hour: u32) -> Result<Option<u32>, CronError> {
+    pub(crate) fn next_hour_match(&self, hour: u32) -> Result<Option<u32>, CronError> {
         if hour > 23 {
             return Err(CronError::InvalidTime);
         }
@@ -462,8 +1096,21 @@ impl CronPattern {
         Ok(None) // No match found within the current range
     }
 
+    // Finds the next month that matches the month part of the cron pattern.
+    pub(crate) fn next_month_match(&self, month: u32) -> Result<Option<u32>, CronError> {
+        if month == 0 || month > 12 {
+            return Err(CronError::InvalidDate);
+        }
+        for next_month in month..=12 {
+            if self.months.is_bit_set(next_month as u8, ALL_BIT)? {
+                return Ok(Some(next_month));
+            }
+        }
+        Ok(None) // No match found within the current year
+    }
+
     // Finds the next minute that matches the minute part of the cron pattern.
-    pub fn next_minute_match(&self, minute: u32) -> Result<Option<u32>, CronError> {
+    pub(crate) fn next_minute_match(&self, minute: u32) -> Result<Option<u32>, CronError> {
         if minute > 59 {
             return Err(CronError::InvalidTime);
         }
@@ -476,7 +1123,7 @@ impl CronPattern {
     }
 
     // Finds the next second that matches the second part of the cron pattern.
-    pub fn next_second_match(&self, second: u32) -> Result<Option<u32>, CronError> {
+    pub(crate) fn next_second_match(&self, second: u32) -> Result<Option<u32>, CronError> {
         if second > 59 {
             return Err(CronError::InvalidTime);
         }
@@ -488,40 +1135,467 @@ impl CronPattern {
         Ok(None) // No match found within the current range
     }
 
+    // Finds the previous hour that matches the hour part of the cron pattern.
+    pub(crate) fn previous_hour_match(&self, hour: u32) -> Result<Option<u32>, CronError> {
+        if hour > 23 {
+            return Err(CronError::InvalidTime);
+        }
+        Ok(self
+            .hours
+            .iter_matching(ALL_BIT, Direction::Descending)
+            .map(u32::from)
+            .find(|&previous_hour| previous_hour <= hour))
+    }
+
+    // Finds the previous minute that matches the minute part of the cron pattern.
+    pub(crate) fn previous_minute_match(&self, minute: u32) -> Result<Option<u32>, CronError> {
+        if minute > 59 {
+            return Err(CronError::InvalidTime);
+        }
+        Ok(self
+            .minutes
+            .iter_matching(ALL_BIT, Direction::Descending)
+            .map(u32::from)
+            .find(|&previous_minute| previous_minute <= minute))
+    }
+
+    // Finds the previous second that matches the second part of the cron pattern.
+    pub(crate) fn previous_second_match(&self, second: u32) -> Result<Option<u32>, CronError> {
+        if second > 59 {
+            return Err(CronError::InvalidTime);
+        }
+        Ok(self
+            .seconds
+            .iter_matching(ALL_BIT, Direction::Descending)
+            .map(u32::from)
+            .find(|&previous_second| previous_second <= second))
+    }
+
     // Method to set the dom_and_dow flag
-    pub fn with_dom_and_dow(&mut self) -> &mut Self {
+    pub(crate) fn with_dom_and_dow(&mut self) -> &mut Self {
         self.dom_and_dow = true;
         self
     }
 
     // Method to set wether seconds should be allowed
-    pub fn with_seconds_optional(&mut self) -> &mut Self {
+    pub(crate) fn with_seconds_optional(&mut self) -> &mut Self {
         self.with_seconds_optional = true;
         self
     }
 
     // Method to set wether seconds should be allowed
-    pub fn with_seconds_required(&mut self) -> &mut Self {
+    pub(crate) fn with_seconds_required(&mut self) -> &mut Self {
         self.with_seconds_required = true;
         self
     }
 
     // Method to set if weekdays should be offset by one (Quartz Scheduler style)
-    pub fn with_alternative_weekdays(&mut self) -> &mut Self {
+    pub(crate) fn with_alternative_weekdays(&mut self) -> &mut Self {
         self.with_alternative_weekdays = true;
         //  We need to recreate self.days_of_week
-        self.days_of_week = CronComponent::new(0, 7, LAST_BIT | NTH_ALL, 1);
+        self.days_of_week = CronComponent::new(0, 7, LAST_BIT | NTH_ALL, 1).with_wrapping();
         self
     }
 
-    // Get a reference to the original pattern
+    // Method to parse Quartz expressions: seconds are required, weekdays run from 1 (Sunday) to
+    // 7 (Saturday), steps may start from a single value like 0/5 and an optional trailing year
+    // field is accepted when it is a wildcard
+    pub(crate) fn with_quartz(&mut self) -> &mut Self {
+        self.with_quartz = true;
+        self.with_seconds_required();
+        self.with_alternative_weekdays()
+    }
+
+    // Method to clamp out-of-range values into their field instead of rejecting the pattern.
+    // Every adjustment is recorded and available through warnings() after parsing
+    pub(crate) fn with_lenient(&mut self) -> &mut Self {
+        self.lenient = true;
+        self
+    }
+
+    /// Returns the adjustments made while parsing leniently, like "minute: 61 clamped to 59".
+    pub fn warnings(&self) -> &[String] {
+        &self.warnings
+    }
+
+    // Method to set the seed used when resolving hashed (H) values. Parsing writes the resolved
+    // values back into the pattern, so as_str() returns them instead of H
+    pub(crate) fn with_hash_seed(&mut self, seed: u64) -> &mut Self {
+        self.hash_seed = seed;
+        self
+    }
+
+    // Method to set the maximum number of times the pattern may fire per day
+    pub(crate) fn with_max_fires_per_day(&mut self, max_fires_per_day: u64) -> &mut Self {
+        self.max_fires_per_day = Some(max_fires_per_day);
+        self
+    }
+
+    // Method to reject patterns whose days of month never exist in the selected months
+    pub(crate) fn with_reject_impossible(&mut self) -> &mut Self {
+        self.reject_impossible = true;
+        self
+    }
+
+    // Method to accept weekday and month names of the given language, besides the English ones
+    pub(crate) fn with_locale(&mut self, locale: Locale) -> &mut Self {
+        self.locale = locale;
+        self
+    }
+
+    // Checks whether the day of month can match in any of the selected months, as far as the day
+    // of month alone decides the day. February is counted with 29 days, as leap years have them.
+    // L and L-n always find a day, while W only finds one when its day exists in the month
+    fn days_exist_in_months(&self) -> bool {
+        let dom_decides = if self.q_dom != self.q_dow {
+            self.q_dow
+        } else if self.star_dom || self.star_dow {
+            !self.star_dom
+        } else {
+            self.dom_and_dow
+        };
+        let last_days =
+            self.days.is_feature_enabled(LAST_BIT) || self.days.count_set_values(LAST_BIT) > 0;
+        if !dom_decides || last_days {
+            return true;
+        }
+        let first_day = [ALL_BIT, CLOSEST_WEEKDAY_BIT]
+            .into_iter()
+            .filter_map(|bit| self.days.iter_matching(bit, Direction::Ascending).next())
+            .min();
+        let Some(first_day) = first_day else {
+            return false;
+        };
+        self.months
+            .iter_matching(ALL_BIT, Direction::Ascending)
+            .any(|month| {
+                let longest = match month {
+                    2 => 29,
+                    4 | 6 | 9 | 11 => 30,
+                    _ => 31,
+                };
+                first_day <= longest
+            })
+    }
+
+    // Method to set how times skipped by a daylight saving transition are handled
+    pub(crate) fn with_dst_gap(&mut self, policy: DstGapPolicy) -> &mut Self {
+        self.dst_gap = policy;
+        self
+    }
+
+    // Method to set how times repeated by a daylight saving transition are handled
+    pub(crate) fn with_dst_overlap(&mut self, policy: DstOverlapPolicy) -> &mut Self {
+        self.dst_overlap = policy;
+        self
+    }
+
+    /// Whether the day-of-month field is a wildcard, which decides how the day fields combine.
+    pub fn star_dom(&self) -> bool {
+        self.star_dom
+    }
+
+    /// Whether the day-of-week field is a wildcard, which decides how the day fields combine.
+    pub fn star_dow(&self) -> bool {
+        self.star_dow
+    }
+
+    /// Whether the day-of-month field was given as '?' ("no specific value"), which unlike '*'
+    /// always leaves the day to the day-of-week field.
+    pub fn q_dom(&self) -> bool {
+        self.q_dom
+    }
+
+    /// Whether the day-of-week field was given as '?' ("no specific value"), which unlike '*'
+    /// always leaves the day to the day-of-month field.
+    pub fn q_dow(&self) -> bool {
+        self.q_dow
+    }
+
+    /// Whether both the day of month and the day of week have to match, instead of either.
+    pub fn dom_and_dow(&self) -> bool {
+        self.dom_and_dow
+    }
+
+    /// Whether a seconds field is accepted, making 6-part patterns valid.
+    pub fn seconds_optional(&self) -> bool {
+        self.with_seconds_optional
+    }
+
+    /// Whether a seconds field is required, making 5-part patterns invalid.
+    pub fn seconds_required(&self) -> bool {
+        self.with_seconds_required
+    }
+
+    /// Whether weekdays run from 1 (Sunday) to 7 (Saturday), as in Quartz, instead of from 0.
+    pub fn alternative_weekdays(&self) -> bool {
+        self.with_alternative_weekdays
+    }
+
+    /// Whether the pattern was parsed as a Quartz expression.
+    pub fn quartz(&self) -> bool {
+        self.with_quartz
+    }
+
+    /// Whether out-of-range values were clamped instead of rejected, see [`warnings`](Self::warnings).
+    pub fn lenient(&self) -> bool {
+        self.lenient
+    }
+
+    /// Whether day and month combinations that never exist are rejected.
+    pub fn reject_impossible(&self) -> bool {
+        self.reject_impossible
+    }
+
+    /// The seed used to resolve hashed (H) values.
+    pub fn hash_seed(&self) -> u64 {
+        self.hash_seed
+    }
+
+    /// The maximum number of times the pattern may fire per day, if limited.
+    pub fn max_fires_per_day(&self) -> Option<u64> {
+        self.max_fires_per_day
+    }
+
+    /// How times skipped by a daylight saving transition are handled.
+    pub fn dst_gap(&self) -> DstGapPolicy {
+        self.dst_gap
+    }
+
+    /// How times repeated by a daylight saving transition are handled.
+    pub fn dst_overlap(&self) -> DstOverlapPolicy {
+        self.dst_overlap
+    }
+
+    /// The language of the weekday and month names accepted besides the English ones.
+    pub fn locale(&self) -> Locale {
+        self.locale
+    }
+
+    /// Returns the pattern as written, with hashed (H) values resolved.
     pub fn as_str(&self) -> &str {
         &self.pattern
     }
+
+    // Builds the schedule key from the canonical pattern. Unparsed patterns only compare by text
+    pub(crate) fn schedule_key(&self) -> ScheduleKey {
+        if !self.is_parsed {
+            return (
+                self.pattern.clone(),
+                self.dom_and_dow,
+                self.dst_gap,
+                self.dst_overlap,
+            );
+        }
+        let normalized = self.with_nth_weekdays_normalized();
+        let both_restricted = !normalized.star_dom && !normalized.star_dow;
+        (
+            self.canonical(),
+            normalized.dom_and_dow && both_restricted,
+            self.dst_gap,
+            self.dst_overlap,
+        )
+    }
+
+    // Rewrites one of the first four weeks of the month combined with weekdays using AND, like
+    // "1-7" and "MON", into the nth weekday it describes, "MON#1"
+    fn with_nth_weekdays_normalized(&self) -> CronPattern {
+        let mut normalized = self.clone();
+        let plain_days = !self.days.is_feature_enabled(LAST_BIT)
+            && self.days.get_set_values(LAST_BIT).is_empty()
+            && self.days.get_set_values(CLOSEST_WEEKDAY_BIT).is_empty();
+        let plain_weekdays = self
+            .days_of_week
+            .get_set_values(LAST_BIT | NTH_ALL)
+            .is_empty();
+        if !self.dom_and_dow || self.star_dom || self.star_dow || !plain_days || !plain_weekdays {
+            return normalized;
+        }
+
+        let days = self.days.get_set_values(ALL_BIT);
+        let nth_bit = match days[..] {
+            [first, .., last] if days.len() == 7 && last == first + 6 => match first {
+                1 => NTH_1ST_BIT,
+                8 => NTH_2ND_BIT,
+                15 => NTH_3RD_BIT,
+                22 => NTH_4TH_BIT,
+                _ => return normalized,
+            },
+            _ => return normalized,
+        };
+        for weekday in self.days_of_week.get_set_values(ALL_BIT) {
+            // Setting bits within the component range can not fail
+            let _ = normalized.days_of_week.unset_bit(weekday, ALL_BIT);
+            let _ = normalized.days_of_week.set_bit(weekday, nth_bit);
+        }
+        normalized.star_dom = true;
+        normalized
+    }
+
+    /// Renders a normalized six-field pattern from the parsed components, so different spellings of
+    /// the same fields render identically, like "@daily" and "0 0 * * *" both becoming
+    /// "0 0 0 * * *". Fields are not merged across each other, so "0 0 1-31 * *" still differs from
+    /// "0 0 * * *". Parser options such as dom_and_dow are not included, but a week of days combined
+    /// with weekdays using dom_and_dow renders as the nth weekday it describes.
+    pub fn canonical(&self) -> String {
+        self.canonical_fields().join(" ")
+    }
+
+    /// Renders each of the six fields in normalized form, seconds first, see [`canonical`](Self::canonical).
+    pub fn canonical_fields(&self) -> [String; 6] {
+        self.with_nth_weekdays_normalized().rendered_fields()
+    }
+
+    // Renders each of the six fields of the components as they are
+    fn rendered_fields(&self) -> [String; 6] {
+        // Day of month and day of week only render as a wildcard when they were one, as that
+        // decides how the two fields combine
+        let days = if self.star_dom {
+            "*".to_string()
+        } else {
+            let mut entries = canonical_runs(&self.days.get_set_values(ALL_BIT));
+            if self.days.is_feature_enabled(LAST_BIT) {
+                entries.push("L".to_string());
+            }
+            for offset in self.days.get_set_values(LAST_BIT) {
+                entries.push(format!("L-{}", offset));
+            }
+            for day in self.days.get_set_values(CLOSEST_WEEKDAY_BIT) {
+                entries.push(format!("{}W", day));
+            }
+            entries.join(",")
+        };
+
+        let days_of_week = if self.star_dow {
+            "*".to_string()
+        } else {
+            // Position 7 is an alias of Sunday which is never consulted when matching
+            let weekdays = |bit| {
+                self.days_of_week
+                    .get_set_values(bit)
+                    .into_iter()
+                    .filter(|weekday| *weekday <= 6)
+                    .collect::<Vec<u8>>()
+            };
+            let mut entries = canonical_runs(&weekdays(ALL_BIT));
+            let mut nth_entries = Vec::new();
+            for (nth, bit) in (1..=5).zip([
+                NTH_1ST_BIT,
+                NTH_2ND_BIT,
+                NTH_3RD_BIT,
+                NTH_4TH_BIT,
+                NTH_5TH_BIT,
+            ]) {
+                nth_entries.extend(weekdays(bit).into_iter().map(|weekday| (weekday, nth)));
+            }
+            nth_entries.sort_unstable();
+            for (weekday, nth) in nth_entries {
+                entries.push(format!("{}#{}", weekday, nth));
+            }
+            for weekday in weekdays(LAST_BIT) {
+                entries.push(format!("{}L", weekday));
+            }
+            entries.join(",")
+        };
+
+        [
+            canonical_field(&self.seconds),
+            canonical_field(&self.minutes),
+            canonical_field(&self.hours),
+            days,
+            canonical_field(&self.months),
+            days_of_week,
+        ]
+    }
 }
 
-impl std::fmt::Display for CronPattern {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+// Clamps plain numbers and the bounds of ranges within a field into the accepted range, leaving
+// steps and special entries like L or 5#2 as they are. Every adjusted entry is reported in warnings
+fn clamp_field(
+    text: &str,
+    field: CronField,
+    range: (u8, u8),
+    warnings: &mut Vec<String>,
+) -> String {
+    let (low, high) = (u64::from(range.0), u64::from(range.1));
+    let clamp = |number: &str| -> Option<String> {
+        if number.is_empty() || !number.chars().all(|c| c.is_ascii_digit()) {
+            return None;
+        }
+        // Numbers too long to parse are far above the range
+        let value = number.parse::<u64>().unwrap_or(u64::MAX);
+        Some(value.clamp(low, high).to_string())
+    };
+
+    text.split(',')
+        .map(|entry| {
+            let (value, step) = match entry.split_once('/') {
+                Some((value, step)) => (value, Some(step)),
+                None => (entry, None),
+            };
+            let clamped = match (clamp(value), value.split_once('-')) {
+                (Some(number), _) => number,
+                (None, Some((start, end))) => match (clamp(start), clamp(end)) {
+                    (Some(start), Some(end)) => format!("{}-{}", start, end),
+                    _ => value.to_string(),
+                },
+                (None, None) => value.to_string(),
+            };
+            let clamped = match step {
+                Some(step) => format!("{}/{}", clamped, step),
+                None => clamped,
+            };
+            if clamped != entry {
+                warnings.push(format!("{field}: {entry} clamped to {clamped}"));
+            }
+            clamped
+        })
+        .collect::<Vec<String>>()
+        .join(",")
+}
+
+// Renders the values of a field without special meaning for wildcards, using "*" for the full
+// range and "*/n" for a step covering it, like "*/15" for minutes 0,15,30,45
+pub(crate) fn canonical_field(component: &CronComponent) -> String {
+    let values = component.get_set_values(ALL_BIT);
+    if values.iter().copied().eq(component.min..=component.max) {
+        return "*".to_string();
+    }
+    if let [first, second, ..] = values.as_slice() {
+        let step = (second - first) as usize;
+        if *first == component.min
+            && values
+                .iter()
+                .copied()
+                .eq((component.min..=component.max).step_by(step))
+        {
+            return format!("*/{}", step);
+        }
+    }
+    canonical_runs(&values).join(",")
+}
+
+// Collapses ascending values into entries, runs of three or more consecutive values become ranges
+pub(crate) fn canonical_runs(values: &[u8]) -> Vec<String> {
+    let mut entries = Vec::new();
+    let mut start = 0;
+    while start < values.len() {
+        let mut end = start;
+        while end + 1 < values.len() && values[end + 1] == values[end] + 1 {
+            end += 1;
+        }
+        if end - start >= 2 {
+            entries.push(format!("{}-{}", values[start], values[end]));
+        } else {
+            entries.extend(values[start..=end].iter().map(|value| value.to_string()));
+        }
+        start = end + 1;
+    }
+    entries
+}
+
+impl core::fmt::Display for CronPattern {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(f, "{}", self.pattern)
     }
 }
@@ -583,10 +1657,10 @@ mod tests {
 
     #[test]
     fn test_cron_pattern_short() {
-        let mut pattern = CronPattern::new("5/5 * * * *");
+        let mut pattern = CronPattern::new("5-59/5 * * * *");
         let result = pattern.parse();
         assert!(result.is_ok());
-        assert_eq!(pattern.pattern, "5/5 * * * *");
+        assert_eq!(pattern.pattern, "5-59/5 * * * *");
         assert!(pattern.seconds.is_bit_set(0, ALL_BIT).unwrap());
         assert!(!pattern.seconds.is_bit_set(5, ALL_BIT).unwrap());
         assert!(pattern.minutes.is_bit_set(5, ALL_BIT).unwrap());
@@ -633,6 +1707,169 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_cron_pattern_tolerates_stray_commas() -> Result<(), CronError> {
+        for expr in [
+            "1,2, * * * *",
+            ",1,2 * * * *",
+            "1,,2 * * * *",
+            ",1,2, * * * *",
+        ] {
+            let pattern = CronPattern::new(expr).parse()?;
+            for minute in 0..=59 {
+                assert_eq!(
+                    pattern.minutes.is_bit_set(minute, ALL_BIT)?,
+                    minute == 1 || minute == 2
+                );
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_redundancies() -> Result<(), CronError> {
+        let pattern = CronPattern::new("1-5,3-7 * * * *").parse()?;
+        assert_eq!(pattern.redundancies(), ["minute: 3-7 overlaps 1-5"]);
+
+        let pattern = CronPattern::new("0 */6,12 1,1 * MON-FRI,WED").parse()?;
+        assert_eq!(
+            pattern.redundancies(),
+            [
+                "hour: 12 is covered by */6",
+                "day-of-month: 1 is covered by 1",
+                "day-of-week: 3 is covered by 1-5",
+            ]
+        );
+
+        let pattern = CronPattern::new("0,30 9-17 * * *").parse()?;
+        assert!(pattern.redundancies().is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_locale_names() -> Result<(), CronError> {
+        let weekdays = |text: &str, locale: Locale| -> Result<Vec<u8>, CronError> {
+            let pattern = CronPattern::new(text).with_locale(locale).parse()?;
+            Ok(pattern.days_of_week.get_set_values(ALL_BIT))
+        };
+
+        assert_eq!(
+            weekdays("0 0 * * MÅN-FRE", Locale::Swedish)?,
+            [1, 2, 3, 4, 5]
+        );
+        assert_eq!(
+            weekdays("0 0 * * mån-fre", Locale::Swedish)?,
+            [1, 2, 3, 4, 5]
+        );
+        assert_eq!(weekdays("0 0 * * LÖR-SÖN", Locale::Swedish)?, [0, 6]);
+        assert_eq!(weekdays("0 0 * * TIS,TOR", Locale::Swedish)?, [2, 4]);
+        assert_eq!(weekdays("0 0 * * MO-FR", Locale::German)?, [1, 2, 3, 4, 5]);
+        assert_eq!(weekdays("0 0 * * SO#2", Locale::German)?, Vec::<u8>::new());
+
+        // English names are still accepted, also where they contain a localized name
+        assert_eq!(
+            weekdays("0 0 * * MON-FRE", Locale::Swedish)?,
+            [1, 2, 3, 4, 5]
+        );
+        assert_eq!(weekdays("0 0 * * MON,SUN", Locale::German)?, [0, 1]);
+
+        // Localized names work with Quartz-style weekdays as well
+        let pattern = CronPattern::new("0 0 0 ? * MÅN")
+            .with_quartz()
+            .with_locale(Locale::Swedish)
+            .parse()?;
+        assert_eq!(pattern.days_of_week.get_set_values(ALL_BIT), [1]);
+
+        let pattern = CronPattern::new("0 0 1 MAJ,OKT *")
+            .with_locale(Locale::Swedish)
+            .parse()?;
+        assert_eq!(pattern.months.get_set_values(ALL_BIT), [5, 10]);
+        let pattern = CronPattern::new("0 0 1 MÄR-MAI,DEZ *")
+            .with_locale(Locale::German)
+            .parse()?;
+        assert_eq!(pattern.months.get_set_values(ALL_BIT), [3, 4, 5, 12]);
+
+        // Localized names are only accepted for the configured locale
+        assert!(CronPattern::new("0 0 * * MÅN").parse().is_err());
+        assert!(CronPattern::new("0 0 * * MO")
+            .with_locale(Locale::Swedish)
+            .parse()
+            .is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_reject_impossible() {
+        let parse = |text: &str| CronPattern::new(text).with_reject_impossible().parse();
+
+        for impossible in [
+            "0 0 30 2 *",
+            "0 0 30,31 2 *",
+            "0 0 31 4,6,9,11 *",
+            "0 0 31 2,4 ?",
+            "0 0 30W 2 *",
+            "0 0 31W 4 *",
+        ] {
+            assert!(
+                matches!(parse(impossible), Err(CronError::InvalidPattern(_))),
+                "{impossible}"
+            );
+        }
+
+        // Rare but possible, or decided by the day of week as well
+        for possible in [
+            "0 0 29 2 *",
+            "0 0 30 1-2 *",
+            "0 0 31 * *",
+            "0 0 L 2 *",
+            "0 0 29W 2 *",
+            "0 0 15W,30W 2 *",
+            "0 0 30 2 MON",
+            "0 0 ? 2 MON",
+        ] {
+            assert!(parse(possible).is_ok(), "{possible}");
+        }
+        assert!(matches!(
+            CronPattern::new("0 0 30 2 MON")
+                .with_dom_and_dow()
+                .with_reject_impossible()
+                .parse(),
+            Err(CronError::InvalidPattern(_))
+        ));
+
+        // Only checked when asked for
+        assert!(CronPattern::new("0 0 30 2 *").parse().is_ok());
+    }
+
+    #[test]
+    fn test_max_fires_per_day() -> Result<(), CronError> {
+        assert_eq!(
+            CronPattern::new("* * * * *")
+                .parse()?
+                .occurrences_per_day_estimate()?,
+            1440
+        );
+        assert_eq!(
+            CronPattern::new("0 */15 9-17 * * MON-FRI")
+                .with_seconds_optional()
+                .parse()?
+                .occurrences_per_day_estimate()?,
+            36
+        );
+
+        assert!(matches!(
+            CronPattern::new("* * * * *")
+                .with_max_fires_per_day(100)
+                .parse(),
+            Err(CronError::InvalidPattern(_))
+        ));
+        assert!(CronPattern::new("*/15 * * * *")
+            .with_max_fires_per_day(100)
+            .parse()
+            .is_ok());
+        Ok(())
+    }
+
     #[test]
     fn test_cron_pattern_leading_zeros() {
         let mut pattern = CronPattern::new("  */15  01 01,15 01    01-05    ");
@@ -686,6 +1923,322 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_cron_pattern_handle_every() -> Result<(), CronError> {
+        assert_eq!(
+            CronPattern::handle_every("@every 15m", false, false)?,
+            "*/15 * * * *"
+        );
+        assert_eq!(
+            CronPattern::handle_every("@every 1m", false, false)?,
+            "* * * * *"
+        );
+        assert_eq!(
+            CronPattern::handle_every("@every 2h", false, false)?,
+            "0 */2 * * *"
+        );
+        assert_eq!(
+            CronPattern::handle_every("@every 1h", false, true)?,
+            "0 0 * * * *"
+        );
+        assert_eq!(
+            CronPattern::handle_every("@every 1d", false, false)?,
+            "0 0 * * *"
+        );
+        assert_eq!(
+            CronPattern::handle_every("@EVERY 120m", false, false)?,
+            "0 */2 * * *"
+        );
+        assert_eq!(
+            CronPattern::handle_every("@every 10s", true, false)?,
+            "*/10 * * * * *"
+        );
+        assert_eq!(
+            CronPattern::handle_every("@every 1h 0m 0s", false, false)?,
+            "0 * * * *"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_cron_pattern_handle_every_invalid() {
+        for pattern in [
+            "@every",
+            "@every 90m",
+            "@every 2h30m",
+            "@every 7m",
+            "@every 2d",
+            "@every 0s",
+            "@every 15",
+            "@every 15x",
+            "@every 10s", // Seconds not allowed by configuration
+        ] {
+            assert!(matches!(
+                CronPattern::handle_every(pattern, false, false),
+                Err(CronError::InvalidPattern(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn test_hashed_values_are_stable() -> Result<(), CronError> {
+        let first = CronPattern::new("H H * * *").with_hash_seed(42).parse()?;
+        let second = CronPattern::new("H H * * *").with_hash_seed(42).parse()?;
+        for minute in 0..=59 {
+            assert_eq!(
+                first.minutes.is_bit_set(minute, ALL_BIT)?,
+                second.minutes.is_bit_set(minute, ALL_BIT)?
+            );
+        }
+        for hour in 0..=23 {
+            assert_eq!(
+                first.hours.is_bit_set(hour, ALL_BIT)?,
+                second.hours.is_bit_set(hour, ALL_BIT)?
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_hashed_values_within_range() -> Result<(), CronError> {
+        for seed in 0..100 {
+            let pattern = CronPattern::new("H(0-30) H(9-17) H * *")
+                .with_hash_seed(seed)
+                .parse()?;
+            let minutes: Vec<u8> = (0..=59)
+                .filter(|m| pattern.minutes.is_bit_set(*m, ALL_BIT).unwrap())
+                .collect();
+            let hours: Vec<u8> = (0..=23)
+                .filter(|h| pattern.hours.is_bit_set(*h, ALL_BIT).unwrap())
+                .collect();
+            let days: Vec<u8> = (1..=31)
+                .filter(|d| pattern.days.is_bit_set(*d, ALL_BIT).unwrap())
+                .collect();
+            assert!(minutes.len() == 1 && minutes[0] <= 30);
+            assert!(hours.len() == 1 && (9..=17).contains(&hours[0]));
+            assert!(days.len() == 1 && days[0] <= 28);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_hashed_values_are_kept_in_pattern() -> Result<(), CronError> {
+        let pattern = CronPattern::new("H H(9-17) * * *")
+            .with_hash_seed(42)
+            .parse()?;
+        assert!(!pattern.as_str().contains('h'));
+        let reparsed = CronPattern::new(pattern.as_str()).parse()?;
+        assert_eq!(reparsed.canonical(), pattern.canonical());
+
+        // Omitted seconds stay omitted, and a Quartz year stays in place
+        assert_eq!(pattern.as_str().split_whitespace().count(), 5);
+        let pattern = CronPattern::new("0 H 0 * * ? *")
+            .with_quartz()
+            .with_hash_seed(42)
+            .parse()?;
+        assert_eq!(pattern.as_str().split_whitespace().count(), 7);
+        assert!(pattern.as_str().ends_with(" *"));
+
+        // Every H in a field resolves to the same position within its range
+        let pattern = CronPattern::new("H(0-29),H(30-59) * * * *")
+            .with_hash_seed(42)
+            .parse()?;
+        let minutes = pattern.minutes.get_set_values(ALL_BIT);
+        assert_eq!(minutes.len(), 2);
+        assert_eq!(minutes[1], minutes[0] + 30);
+        Ok(())
+    }
+
+    #[test]
+    fn test_hashed_values_invalid() {
+        for pattern in [
+            "0 0 * H *",
+            "0 0 * * H",
+            "H(0-60) * * * *",
+            "H(5) * * * *",
+            "Hx * * * *",
+        ] {
+            assert!(CronPattern::new(pattern).parse().is_err());
+        }
+    }
+
+    #[test]
+    fn test_field_error_points_at_offending_token() {
+        match CronPattern::new("0 60 * * *").parse() {
+            Err(CronError::FieldError {
+                field,
+                part,
+                offset,
+                ..
+            }) => {
+                assert_eq!(field, CronField::Hour);
+                assert_eq!(part, "60");
+                assert_eq!(offset, 2);
+            }
+            other => panic!("Expected a FieldError, got {:?}", other),
+        }
+
+        match CronPattern::new("1,5,61 * * * *").parse() {
+            Err(CronError::FieldError {
+                field,
+                part,
+                offset,
+                ..
+            }) => {
+                assert_eq!(field, CronField::Minute);
+                assert_eq!(part, "61");
+                assert_eq!(offset, 4);
+            }
+            other => panic!("Expected a FieldError, got {:?}", other),
+        }
+
+        // Names, locale names and the AND modifier are reported as written
+        for (text, locale, part, offset) in [
+            ("0 0 * * MON-FRI,9", Locale::English, "9", 16),
+            ("0 0 * * MÅN,9", Locale::Swedish, "9", 13),
+            ("0 0 * JAN,13 *", Locale::English, "13", 10),
+            ("0 0 * * +Mon,9", Locale::English, "9", 13),
+        ] {
+            match CronPattern::new(text).with_locale(locale).parse() {
+                Err(CronError::FieldError {
+                    part: error_part,
+                    offset: error_offset,
+                    ..
+                }) => {
+                    assert_eq!(
+                        (error_part.as_str(), error_offset),
+                        (part, offset),
+                        "{text}"
+                    );
+                    assert_eq!(&text[offset..offset + part.len()], part);
+                }
+                other => panic!("Expected a FieldError for {text}, got {:?}", other),
+            }
+        }
+
+        let error = CronPattern::new("60 * * * *").parse().unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "minute field: '60' at offset 0: Number out of range 0-59."
+        );
+    }
+
+    #[test]
+    fn test_stepped_day_of_month() -> Result<(), CronError> {
+        let days = |text: &str| -> Result<Vec<u8>, CronError> {
+            Ok(CronPattern::new(text).parse()?.days.get_set_values(ALL_BIT))
+        };
+        assert_eq!(days("0 0 1-15/2 * *")?, [1, 3, 5, 7, 9, 11, 13, 15]);
+        assert_eq!(days("0 0 */10 * *")?, [1, 11, 21, 31]);
+
+        // A step from a single start needs a range, except in Quartz expressions where it runs up
+        // to the end of the field
+        let quartz_days = CronPattern::new("0 0 0 15/5 * ?")
+            .with_quartz()
+            .parse()?
+            .days;
+        assert_eq!(quartz_days.get_set_values(ALL_BIT), [15, 20, 25, 30]);
+
+        // Invalid steps name the field and the offending entry
+        for (text, part, reason) in [
+            (
+                "0 0 15/2 * *",
+                "15/2",
+                "Step needs a range or *, like 15-31/2.",
+            ),
+            (
+                "0 0 1-15/2,20/5 * *",
+                "20/5",
+                "Step needs a range or *, like 20-31/5.",
+            ),
+            ("0 0 15/0 * *", "15/0", "Step cannot be zero."),
+            ("0 0 1-15/x * *", "1-15/x", "Invalid step."),
+            ("0 0 1,1-32/2 * *", "1-32/2", "Range out of range 1-31."),
+        ] {
+            let error = CronPattern::new(text).parse().unwrap_err();
+            assert!(
+                matches!(
+                    &error,
+                    CronError::FieldError { field: CronField::DayOfMonth, part: p, .. } if p == part
+                ),
+                "{text}: {error:?}"
+            );
+            assert!(
+                error.to_string().starts_with("day-of-month field"),
+                "{text}"
+            );
+            assert!(error.to_string().ends_with(reason), "{text}: {error}");
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_zero_and_oversized_steps() {
+        for (text, field, part, reason) in [
+            (
+                "0 */0 * * *",
+                CronField::Hour,
+                "*/0",
+                "Step cannot be zero.",
+            ),
+            (
+                "*/0 * * * *",
+                CronField::Minute,
+                "*/0",
+                "Step cannot be zero.",
+            ),
+            (
+                "0 0 * 1-12/0 *",
+                CronField::Month,
+                "1-12/0",
+                "Step cannot be zero.",
+            ),
+            (
+                "*/120 * * * *",
+                CronField::Minute,
+                "*/120",
+                "Step 120 is larger than the range of the field (0-59).",
+            ),
+            (
+                "0 5/25 * * *",
+                CronField::Hour,
+                "5/25",
+                "Step 25 is larger than the range of the field (0-23).",
+            ),
+            (
+                "0 0 * */13 *",
+                CronField::Month,
+                "*/13",
+                "Step 13 is larger than the range of the field (1-12).",
+            ),
+            (
+                "0 0 * * 1,*/9",
+                CronField::DayOfWeek,
+                "*/9",
+                "Step 9 is larger than the range of the field (0-7).",
+            ),
+        ] {
+            match CronPattern::new(text).parse() {
+                Err(CronError::FieldError {
+                    field: error_field,
+                    part: error_part,
+                    reason: error_reason,
+                    ..
+                }) => {
+                    assert_eq!(error_field, field, "{text}");
+                    assert_eq!(error_part, part, "{text}");
+                    assert_eq!(error_reason, reason, "{text}");
+                }
+                other => panic!("Expected a FieldError for {text}, got {other:?}"),
+            }
+        }
+
+        // A step as large as the number of values is allowed, and reaches the first value only
+        let pattern = CronPattern::new("*/60 0 */31 * *").parse().unwrap();
+        assert_eq!(pattern.minutes.get_set_values(ALL_BIT), [0]);
+        assert_eq!(pattern.days.get_set_values(ALL_BIT), [1]);
+    }
+
     #[test]
     fn test_month_nickname_range() {
         let mut pattern = CronPattern::new("0 0 * FEB-MAR *");
@@ -704,6 +2257,42 @@ mod tests {
         assert!(pattern.days_of_week.is_bit_set(6, ALL_BIT).unwrap()); // Saturday
     }
 
+    #[test]
+    fn test_wrapping_ranges() -> Result<(), CronError> {
+        let pattern = CronPattern::new("50-10 22-2 * NOV-FEB FRI-MON").parse()?;
+        assert!(pattern.minutes.is_bit_set(55, ALL_BIT)?);
+        assert!(pattern.minutes.is_bit_set(5, ALL_BIT)?);
+        assert!(!pattern.minutes.is_bit_set(30, ALL_BIT)?);
+        assert!(pattern.hours.is_bit_set(23, ALL_BIT)?);
+        assert!(!pattern.hours.is_bit_set(12, ALL_BIT)?);
+        assert!(pattern.months.is_bit_set(12, ALL_BIT)?);
+        assert!(pattern.months.is_bit_set(1, ALL_BIT)?);
+        assert!(!pattern.months.is_bit_set(6, ALL_BIT)?);
+        for (weekday, expected) in [0, 1, 5, 6].iter().map(|d| (*d, true)).chain([(2, false)]) {
+            assert_eq!(pattern.days_of_week.is_bit_set(weekday, ALL_BIT)?, expected);
+        }
+
+        // Weekdays wrap from SAT to SUN, with 7 counted as SUN
+        let pattern = CronPattern::new("0 0 * * 5-2/2").parse()?;
+        for weekday in 0..=6 {
+            let expected = [0, 2, 5].contains(&weekday);
+            assert_eq!(pattern.days_of_week.is_bit_set(weekday, ALL_BIT)?, expected);
+        }
+
+        // Quartz-style weekdays wrap from 7 (SAT) to 1 (SUN)
+        let pattern = CronPattern::new("0 0 * * 6-2")
+            .with_alternative_weekdays()
+            .parse()?;
+        for (weekday, expected) in [(5, true), (6, true), (0, true), (1, true), (2, false)] {
+            assert_eq!(pattern.days_of_week.is_bit_set(weekday, ALL_BIT)?, expected);
+        }
+
+        // Day of month can not wrap
+        assert!(CronPattern::new("0 0 28-2 * *").parse().is_err());
+
+        Ok(())
+    }
+
     #[test]
     fn test_closest_weekday() -> Result<(), CronError> {
         // Example cron pattern: "0 0 15W * *" which means at 00:00 on the closest weekday to the 15th of each month
@@ -733,6 +2322,26 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_closest_weekday_in_day_of_week_field() {
+        for pattern in ["0 0 * * 5W", "0 0 * * 1,15w", "0 0 0 * * MON-FRI,3W"] {
+            let result = CronPattern::new(pattern).with_seconds_optional().parse();
+            assert!(
+                matches!(
+                    result,
+                    Err(CronError::InvalidPattern(ref message))
+                        if message == "W (nearest weekday) is only valid in the day-of-month field."
+                ),
+                "{}",
+                pattern
+            );
+        }
+
+        // Weekday names containing a W are not affected
+        assert!(CronPattern::new("0 0 * * WED").parse().is_ok());
+        assert!(CronPattern::new("0 0 15W * *").parse().is_ok());
+    }
+
     #[test]
     fn test_closest_weekday_with_alternative_weekdays() -> Result<(), CronError> {
         // Example cron pattern: "0 0 15W * *" which means at 00:00 on the closest weekday to the 15th of each month
@@ -867,7 +2476,244 @@ mod tests {
         let mut pattern = CronPattern::new("* * * * 0");
         pattern.with_alternative_weekdays();
 
-        // Parsing should raise a ComponentError
-        assert!(matches!(pattern.parse(), Err(CronError::ComponentError(_))));
+        // Parsing should raise a FieldError pointing at the weekday
+        assert!(matches!(
+            pattern.parse(),
+            Err(CronError::FieldError {
+                field: CronField::DayOfWeek,
+                ref part,
+                ..
+            }) if part == "0"
+        ));
+    }
+
+    #[test]
+    fn test_out_of_range_weekdays_with_alternative_weekdays_fail() {
+        for pattern in [
+            "* * * * 8",
+            "* * * * 0-3",
+            "* * * * 0/2",
+            "* * * * 0#2",
+            "* * * * -1",
+        ] {
+            let mut pattern = CronPattern::new(pattern);
+            pattern.with_alternative_weekdays();
+            assert!(pattern.parse().is_err(), "{} should be rejected", pattern);
+        }
+
+        // The reason names the weekdays accepted with alternative weekdays
+        let mut pattern = CronPattern::new("* * * * 8");
+        pattern.with_alternative_weekdays();
+        assert_eq!(
+            pattern.parse().unwrap_err().to_string(),
+            "day-of-week field: '8' at offset 8: Number out of range 1-7."
+        );
+
+        let mut pattern = CronPattern::new("* * * * */2");
+        pattern.with_alternative_weekdays();
+        assert!(pattern.parse().is_ok());
+    }
+
+    #[test]
+    fn test_next_month_match() {
+        let pattern = CronPattern::new("0 0 1 3,9 *").parse().unwrap();
+        assert_eq!(pattern.next_month_match(1).unwrap(), Some(3));
+        assert_eq!(pattern.next_month_match(3).unwrap(), Some(3));
+        assert_eq!(pattern.next_month_match(4).unwrap(), Some(9));
+        assert_eq!(pattern.next_month_match(10).unwrap(), None);
+        assert!(pattern.next_month_match(13).is_err());
+    }
+
+    #[test]
+    fn test_canonical() {
+        let canonical = |pattern: &str| CronPattern::new(pattern).parse().unwrap().canonical();
+        assert_eq!(canonical("@daily"), "0 0 0 * * *");
+        assert_eq!(canonical("0 0 * * *"), canonical("@daily"));
+        assert_eq!(canonical("0 0 * * 0"), "0 0 0 * * 0");
+        assert_eq!(canonical("0 0 * * SUN"), "0 0 0 * * 0");
+        assert_eq!(canonical("0 0 * * 7"), "0 0 0 * * 0");
+        assert_eq!(canonical("@weekly"), "0 0 0 * * 0");
+        assert_eq!(canonical("0,15,30,45 * * * *"), "0 */15 * * * *");
+        assert_eq!(canonical("5,1,2,3 */6 * JAN-MAR *"), "0 1-3,5 */6 * 1-3 *");
+        assert_eq!(canonical("0 0 1-31 * *"), "0 0 0 1-31 * *");
+        assert_eq!(canonical("0 0 L,15W,L-2,1 * *"), "0 0 0 1,L,L-2,15W * *");
+        assert_eq!(
+            canonical("0 0 * * FRI#L,MON#2,SUN#1"),
+            "0 0 0 * * 0#1,1#2,5L"
+        );
+
+        // The canonical form parses back into the same schedule
+        for pattern in [
+            "0 0 L,15W,L-2,1 * *",
+            "*/7 1-5 * * MON-FRI",
+            "0 0 * * 5L,1#2",
+        ] {
+            let canonical = canonical(pattern);
+            let reparsed = CronPattern::new(&canonical)
+                .with_seconds_required()
+                .parse()
+                .unwrap();
+            assert_eq!(reparsed.canonical(), canonical);
+        }
+    }
+
+    #[test]
+    fn test_lenient_clamping() {
+        let mut pattern = CronPattern::new("61 0-70 32 1-13 *");
+        pattern.with_lenient();
+        let parsed = pattern.parse().unwrap();
+        assert_eq!(
+            parsed.warnings(),
+            [
+                "minute: 61 clamped to 59",
+                "hour: 0-70 clamped to 0-23",
+                "day-of-month: 32 clamped to 31",
+                "month: 1-13 clamped to 1-12",
+            ]
+        );
+        assert_eq!(parsed.canonical(), "0 59 * 31 * *");
+
+        // Steps and special entries are left alone
+        let mut pattern = CronPattern::new("0 0 L,40 * 5#2");
+        pattern.with_lenient();
+        let parsed = pattern.parse().unwrap();
+        assert_eq!(parsed.warnings(), ["day-of-month: 40 clamped to 31"]);
+        assert!(CronPattern::new("0 0 * * 5#9")
+            .with_lenient()
+            .parse()
+            .is_err());
+
+        // Alternative weekdays clamp into 1-7
+        let mut pattern = CronPattern::new("0 0 0 * * 0");
+        pattern
+            .with_seconds_required()
+            .with_alternative_weekdays()
+            .with_lenient();
+        let parsed = pattern.parse().unwrap();
+        assert_eq!(parsed.warnings(), ["day-of-week: 0 clamped to 1"]);
+        let mut pattern = CronPattern::new("0 0 0 * * 9");
+        pattern
+            .with_seconds_required()
+            .with_alternative_weekdays()
+            .with_lenient();
+        let parsed = pattern.parse().unwrap();
+        assert_eq!(parsed.warnings(), ["day-of-week: 9 clamped to 7"]);
+        assert_eq!(parsed.canonical(), "0 0 0 * * 6");
+
+        // Standard weekdays clamp into 0-7
+        let mut pattern = CronPattern::new("0 0 * * 9");
+        pattern.with_lenient();
+        let parsed = pattern.parse().unwrap();
+        assert_eq!(parsed.warnings(), ["day-of-week: 9 clamped to 7"]);
+        assert_eq!(parsed.canonical(), "0 0 0 * * 0");
+
+        // Strict parsing keeps rejecting out-of-range values, and records no warnings
+        assert!(CronPattern::new("61 * * * *").parse().is_err());
+        assert!(CronPattern::new("0 0 * * *")
+            .parse()
+            .unwrap()
+            .warnings()
+            .is_empty());
+    }
+
+    #[test]
+    fn test_question_mark_day_fields() -> Result<(), CronError> {
+        // 2024-01-15 is a Monday
+        for dom_and_dow in [false, true] {
+            let parse = |text: &str| {
+                let mut pattern = CronPattern::new(text);
+                if dom_and_dow {
+                    pattern.with_dom_and_dow();
+                }
+                pattern.parse()
+            };
+
+            let fifteenth = parse("0 0 15 * ?")?;
+            assert!(!fifteenth.q_dom() && fifteenth.q_dow());
+            assert!(fifteenth.day_match(2024, 1, 15)?);
+            assert!(fifteenth.day_match(2024, 2, 15)?);
+            assert!(!fifteenth.day_match(2024, 1, 22)?);
+
+            let mondays = parse("0 0 ? * MON")?;
+            assert!(mondays.q_dom() && !mondays.q_dow());
+            assert!(mondays.day_match(2024, 1, 15)?);
+            assert!(mondays.day_match(2024, 1, 22)?);
+            assert!(!mondays.day_match(2024, 1, 16)?);
+
+            // A '*' is not a '?', and matches like before
+            let star = parse("0 0 15 * *")?;
+            assert!(!star.q_dom() && !star.q_dow());
+            assert!(star.day_match(2024, 1, 15)?);
+            assert!(!star.day_match(2024, 1, 22)?);
+        }
+
+        // Six-part patterns and Quartz expressions keep track of the right fields
+        let pattern = CronPattern::new("0 0 0 ? * 2").with_quartz().parse()?;
+        assert!(pattern.q_dom() && !pattern.q_dow());
+        let pattern = CronPattern::new("0 0 0 ? * ?")
+            .with_seconds_optional()
+            .parse()?;
+        assert!(pattern.q_dom() && pattern.q_dow());
+        assert!(pattern.day_match(2024, 1, 15)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_plus_modifier() -> Result<(), CronError> {
+        // 2024-01-01 is a Monday, 2024-02-01 a Thursday
+        let pattern = CronPattern::new("0 12 1 * +MON").parse()?;
+        assert!(pattern.dom_and_dow);
+        assert!(pattern.day_match(2024, 1, 1)?);
+        assert!(!pattern.day_match(2024, 1, 8)?);
+        assert!(!pattern.day_match(2024, 2, 1)?);
+
+        // Without the modifier, either field matches
+        let pattern = CronPattern::new("0 12 1 * MON").parse()?;
+        assert!(pattern.day_match(2024, 1, 8)?);
+        assert!(pattern.day_match(2024, 2, 1)?);
+
+        for invalid in [
+            "0 12 +1 * MON",
+            "+0 12 1 * MON",
+            "0 12 1 * MON+",
+            "0 12 1 * ++MON",
+        ] {
+            assert!(
+                matches!(
+                    CronPattern::new(invalid).parse(),
+                    Err(CronError::IllegalCharacters(_))
+                ),
+                "{invalid}"
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_mixed_weekday_list() -> Result<(), CronError> {
+        // In March 2024, Mondays are the 4th, 11th, 18th and 25th, the 2nd Friday is the 8th
+        // and the last Sunday is the 31st
+        let expected = [4, 8, 11, 18, 25, 31];
+        for text in [
+            "0 0 * * MON,FRI#2,SUNL",
+            "0 0 * * SUNL,FRI#2,MON",
+            "0 0 * * 1,5#2,0L",
+            "0 0 * * 1,5#2,7L",
+        ] {
+            let pattern = CronPattern::new(text).parse()?;
+            let matching: Vec<u32> = (1..=31)
+                .filter(|day| pattern.day_match(2024, 3, *day).unwrap())
+                .collect();
+            assert_eq!(matching, expected, "{text}");
+            assert_eq!(pattern.canonical(), "0 0 0 * * 1,5#2,0L", "{text}");
+        }
+
+        // Plain, nth and last entries of the same weekday add up
+        let pattern = CronPattern::new("0 0 * * FRI#1,FRI#3,FRIL").parse()?;
+        let matching: Vec<u32> = (1..=31)
+            .filter(|day| pattern.day_match(2024, 3, *day).unwrap())
+            .collect();
+        assert_eq!(matching, [1, 15, 29]);
+        Ok(())
     }
 }
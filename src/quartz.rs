@@ -0,0 +1,202 @@
+use crate::component::{
+    ALL_BIT, CLOSEST_WEEKDAY_BIT, LAST_BIT, NTH_1ST_BIT, NTH_2ND_BIT, NTH_3RD_BIT, NTH_4TH_BIT,
+    NTH_5TH_BIT,
+};
+use crate::errors::CronError;
+use crate::pattern::{canonical_field, canonical_runs, CronPattern};
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+
+const NTH_BITS: [u8; 5] = [
+    NTH_1ST_BIT,
+    NTH_2ND_BIT,
+    NTH_3RD_BIT,
+    NTH_4TH_BIT,
+    NTH_5TH_BIT,
+];
+
+// Translates a parsed pattern into a Quartz cron expression, like "0 15 10 ? * 2-6". Quartz
+// numbers weekdays 1 (Sunday) to 7 and requires "?" in exactly one of the day fields. Every
+// feature without a Quartz equivalent is listed in the returned error.
+pub fn to_quartz(pattern: &CronPattern) -> Result<String, CronError> {
+    let mut unsupported = Vec::new();
+
+    // Day of month, special entries have to stand alone in Quartz
+    let days = &pattern.days;
+    let mut day_entries = canonical_runs(&days.get_set_values(ALL_BIT));
+    let mut day_specials = Vec::new();
+    if days.is_feature_enabled(LAST_BIT) {
+        day_specials.push("L".to_string());
+    }
+    for offset in days.get_set_values(LAST_BIT) {
+        day_specials.push(format!("L-{}", offset));
+    }
+    for day in days.get_set_values(CLOSEST_WEEKDAY_BIT) {
+        day_specials.push(format!("{}W", day));
+    }
+    if !day_specials.is_empty() && day_entries.len() + day_specials.len() > 1 {
+        unsupported.push("L, L-n and W can not be combined with other days of month");
+    }
+    day_entries.extend(day_specials);
+
+    // Day of week, shifted to 1 (Sunday) through 7. Position 7 is an alias of Sunday which is
+    // never consulted when matching
+    let weekdays = |bit| -> Vec<u8> {
+        pattern
+            .days_of_week
+            .get_set_values(bit)
+            .into_iter()
+            .filter(|weekday| *weekday <= 6)
+            .map(|weekday| weekday + 1)
+            .collect()
+    };
+    let mut weekday_entries = canonical_runs(&weekdays(ALL_BIT));
+    let mut weekday_specials = Vec::new();
+    for (nth, bit) in (1..=5).zip(NTH_BITS) {
+        for weekday in weekdays(bit) {
+            weekday_specials.push(format!("{}#{}", weekday, nth));
+        }
+    }
+    for weekday in weekdays(LAST_BIT) {
+        weekday_specials.push(format!("{}L", weekday));
+    }
+    if !weekday_specials.is_empty() && weekday_entries.len() + weekday_specials.len() > 1 {
+        unsupported.push("# and L can not be combined with other days of week");
+    }
+    weekday_entries.extend(weekday_specials);
+
+    // Quartz needs "?" in one of the day fields, so only one of them may be restricted
+    let (day_part, weekday_part) = match (pattern.star_dom(), pattern.star_dow()) {
+        (true, true) => ("*".to_string(), "?".to_string()),
+        (true, false) => ("?".to_string(), weekday_entries.join(",")),
+        (false, true) => (day_entries.join(","), "?".to_string()),
+        (false, false) => {
+            unsupported.push("day of month and day of week can not both be restricted");
+            (String::new(), String::new())
+        }
+    };
+
+    if !unsupported.is_empty() {
+        return Err(CronError::InvalidPattern(format!(
+            "Pattern can not be expressed as a Quartz expression: {}.",
+            unsupported.join("; ")
+        )));
+    }
+
+    Ok(format!(
+        "{} {} {} {} {} {}",
+        canonical_field(&pattern.seconds),
+        canonical_field(&pattern.minutes),
+        canonical_field(&pattern.hours),
+        day_part,
+        canonical_field(&pattern.months),
+        weekday_part,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Cron;
+
+    fn quartz(pattern: &str) -> Result<String, CronError> {
+        let cron = Cron::new(pattern).with_seconds_optional().parse()?;
+        cron.to_quartz()
+    }
+
+    #[test]
+    fn test_to_quartz_samples() -> Result<(), CronError> {
+        // Samples from the Quartz CronTrigger documentation
+        assert_eq!(quartz("0 0 12 * * *")?, "0 0 12 * * ?");
+        assert_eq!(quartz("0 15 10 * * *")?, "0 15 10 * * ?");
+        assert_eq!(quartz("0 */5 14 * * *")?, "0 */5 14 * * ?");
+        assert_eq!(quartz("0 10,44 14 * 3 WED")?, "0 10,44 14 ? 3 4");
+        assert_eq!(quartz("0 15 10 * * MON-FRI")?, "0 15 10 ? * 2-6");
+        assert_eq!(quartz("0 15 10 15 * *")?, "0 15 10 15 * ?");
+        assert_eq!(quartz("0 15 10 L * *")?, "0 15 10 L * ?");
+        assert_eq!(quartz("0 15 10 L-2 * *")?, "0 15 10 L-2 * ?");
+        assert_eq!(quartz("0 15 10 * * 5L")?, "0 15 10 ? * 6L");
+        assert_eq!(quartz("0 15 10 * * FRI#3")?, "0 15 10 ? * 6#3");
+        assert_eq!(
+            quartz("0 0 12 1-31/5 * *")?,
+            "0 0 12 1,6,11,16,21,26,31 * ?"
+        );
+        assert_eq!(quartz("0 11 11 11 11 *")?, "0 11 11 11 11 ?");
+        assert_eq!(quartz("0 0 12 15W * *")?, "0 0 12 15W * ?");
+
+        // Sunday is 1, whether written as 0 or 7
+        assert_eq!(quartz("0 0 * * 0")?, "0 0 0 ? * 1");
+        assert_eq!(quartz("0 0 * * 7")?, "0 0 0 ? * 1");
+        assert_eq!(quartz("0 0 * * SAT,SUN")?, "0 0 0 ? * 1,7");
+        Ok(())
+    }
+
+    #[test]
+    fn test_to_quartz_unsupported() {
+        let error = |pattern: &str| match quartz(pattern) {
+            Err(CronError::InvalidPattern(message)) => message,
+            other => panic!("Expected an error for {}, got {:?}", pattern, other),
+        };
+        assert_eq!(
+            error("0 0 13 * FRI"),
+            "Pattern can not be expressed as a Quartz expression: day of month and day of week \
+             can not both be restricted."
+        );
+        assert_eq!(
+            error("0 0 1,L * MON#1,FRI"),
+            "Pattern can not be expressed as a Quartz expression: L, L-n and W can not be \
+             combined with other days of month; # and L can not be combined with other days of \
+             week; day of month and day of week can not both be restricted."
+        );
+    }
+
+    #[test]
+    fn test_with_quartz_parsing() -> Result<(), CronError> {
+        use chrono::{TimeZone, Utc};
+
+        let next = |pattern: &str| -> Result<_, CronError> {
+            let cron = Cron::new(pattern).with_quartz().parse()?;
+            let start = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+            cron.find_next_occurrence(&start, false)
+        };
+        let at = |month, day, hour, minute| Utc.with_ymd_and_hms(2024, month, day, hour, minute, 0);
+
+        // 2024-01-01 is a Monday
+        assert_eq!(next("0 0 12 ? * MON-FRI")?, at(1, 1, 12, 0).unwrap());
+        assert_eq!(next("0 0 12 ? * 2-6")?, at(1, 1, 12, 0).unwrap());
+        assert_eq!(next("0 0 12 ? * 1")?, at(1, 7, 12, 0).unwrap());
+        assert_eq!(next("0 15 10 L * ?")?, at(1, 31, 10, 15).unwrap());
+        assert_eq!(next("0 15 10 ? * 6L")?, at(1, 26, 10, 15).unwrap());
+        assert_eq!(next("0 15 10 ? * 6#3")?, at(1, 19, 10, 15).unwrap());
+        assert_eq!(next("0 0 12 15W * ?")?, at(1, 15, 12, 0).unwrap());
+        assert_eq!(next("0 0 12 * * ? *")?, at(1, 1, 12, 0).unwrap());
+
+        assert!(next("0 0 12 * * ? 2025").is_err());
+        assert!(matches!(
+            next("0 0 12 LW * ?"),
+            Err(error) if error.to_string().contains("LW (last weekday of the month) is not supported")
+        ));
+        assert!(next("0 12 * * ?").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_quartz_round_trip() -> Result<(), CronError> {
+        for expression in [
+            "0 0 12 * * ?",
+            "0 15 10 ? * 2-6",
+            "0 15 10 L * ?",
+            "0 15 10 ? * 6L",
+            "0 15 10 ? * 6#3",
+            "0 10,44 14 ? 3 4",
+            "0 0 0 ? * 1,7",
+        ] {
+            let cron = Cron::new(expression).with_quartz().parse()?;
+            assert_eq!(cron.to_quartz()?, expression);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,503 @@
+//! A structured representation of a parsed pattern.
+//!
+//! A [`Cron`] normally (de)serializes as its pattern string. [`CronStructured`] instead lists the
+//! values each field resolved to, which lets consumers that do not have a cron parser inspect a
+//! schedule. Converting back with `Cron::try_from` yields a `Cron` matching the same times.
+//!
+//! With the `serde` feature enabled, `CronStructured` (de)serializes as a struct, and a plain
+//! `Cron` field can use the structured form through `#[serde(with = "croner::structured")]`.
+//!
+//! ```rust
+//! use croner::structured::CronStructured;
+//! use croner::Cron;
+//!
+//! let cron = Cron::new("0 9 * * MON-FRI").parse().expect("Couldn't parse cron string");
+//! let structured = CronStructured::from(&cron);
+//! assert_eq!(structured.hours, vec![9]);
+//! assert_eq!(structured.days_of_week, vec![1, 2, 3, 4, 5]);
+//!
+//! let rebuilt = Cron::try_from(structured).expect("Couldn't rebuild cron");
+//! assert_eq!(rebuilt.pattern().to_string(), "0 0 9 * * 1,2,3,4,5");
+//! ```
+
+use crate::component::{
+    ALL_BIT, CLOSEST_WEEKDAY_BIT, LAST_BIT, NTH_1ST_BIT, NTH_2ND_BIT, NTH_3RD_BIT, NTH_4TH_BIT,
+    NTH_5TH_BIT,
+};
+use crate::errors::CronError;
+use crate::Cron;
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+
+#[cfg(feature = "serde")]
+use core::fmt;
+#[cfg(feature = "serde")]
+use serde::{
+    de::{self, MapAccess, Visitor},
+    ser::SerializeStruct,
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+
+const NTH_BITS: [u8; 5] = [
+    NTH_1ST_BIT,
+    NTH_2ND_BIT,
+    NTH_3RD_BIT,
+    NTH_4TH_BIT,
+    NTH_5TH_BIT,
+];
+
+#[cfg(feature = "serde")]
+const FIELD_NAMES: [&str; 14] = [
+    "seconds",
+    "minutes",
+    "hours",
+    "days_of_month",
+    "last_day_of_month",
+    "last_day_offsets",
+    "closest_weekdays",
+    "months",
+    "days_of_week",
+    "nth_days_of_week",
+    "last_days_of_week",
+    "dom_and_dow",
+    "star_dom",
+    "star_dow",
+];
+
+/// The values each field of a parsed pattern resolved to.
+///
+/// Weekdays are numbered from 0 (Sunday) to 6 (Saturday), regardless of the weekday
+/// numbering used by the original pattern.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CronStructured {
+    /// Matching seconds, 0-59.
+    pub seconds: Vec<u8>,
+    /// Matching minutes, 0-59.
+    pub minutes: Vec<u8>,
+    /// Matching hours, 0-23.
+    pub hours: Vec<u8>,
+    /// Matching days of the month, 1-31.
+    pub days_of_month: Vec<u8>,
+    /// Whether the last day of the month matches, through `L`.
+    pub last_day_of_month: bool,
+    /// Matching offsets from the last day of the month, through `L-n`.
+    pub last_day_offsets: Vec<u8>,
+    /// Days of the month matched on the closest weekday, through `nW`.
+    pub closest_weekdays: Vec<u8>,
+    /// Matching months, 1-12.
+    pub months: Vec<u8>,
+    /// Matching weekdays, 0-6.
+    pub days_of_week: Vec<u8>,
+    /// Matching weekdays of a given occurrence within the month as `(weekday, nth)`, through `#n`.
+    pub nth_days_of_week: Vec<(u8, u8)>,
+    /// Weekdays matching on their last occurrence within the month, through `L`.
+    pub last_days_of_week: Vec<u8>,
+    /// Whether day of month and day of week both have to match.
+    pub dom_and_dow: bool,
+    /// Whether the day of month field was a wildcard.
+    pub star_dom: bool,
+    /// Whether the day of week field was a wildcard.
+    pub star_dow: bool,
+}
+
+impl From<&Cron> for CronStructured {
+    fn from(cron: &Cron) -> Self {
+        let pattern = &cron.pattern;
+        let days = &pattern.days;
+        let days_of_week = &pattern.days_of_week;
+
+        // Position 7 is an alias of Sunday which is never consulted when matching
+        let weekdays = |bit| {
+            days_of_week
+                .get_set_values(bit)
+                .into_iter()
+                .filter(|weekday| *weekday <= 6)
+                .collect::<Vec<u8>>()
+        };
+        let nth_days_of_week = (1..=5)
+            .zip(NTH_BITS)
+            .flat_map(|(nth, bit)| weekdays(bit).into_iter().map(move |weekday| (weekday, nth)))
+            .collect();
+
+        CronStructured {
+            seconds: pattern.seconds.get_set_values(ALL_BIT),
+            minutes: pattern.minutes.get_set_values(ALL_BIT),
+            hours: pattern.hours.get_set_values(ALL_BIT),
+            days_of_month: days.get_set_values(ALL_BIT),
+            last_day_of_month: days.is_feature_enabled(LAST_BIT),
+            last_day_offsets: days.get_set_values(LAST_BIT),
+            closest_weekdays: days.get_set_values(CLOSEST_WEEKDAY_BIT),
+            months: pattern.months.get_set_values(ALL_BIT),
+            days_of_week: weekdays(ALL_BIT),
+            nth_days_of_week,
+            last_days_of_week: weekdays(LAST_BIT),
+            dom_and_dow: pattern.dom_and_dow,
+            star_dom: pattern.star_dom(),
+            star_dow: pattern.star_dow(),
+        }
+    }
+}
+
+impl TryFrom<CronStructured> for Cron {
+    type Error = CronError;
+
+    // Renders the structure as a six field pattern and parses it, so the values are validated
+    // exactly like a pattern string would be
+    fn try_from(structured: CronStructured) -> Result<Cron, CronError> {
+        let join = |values: &[u8]| {
+            values
+                .iter()
+                .map(|value| value.to_string())
+                .collect::<Vec<String>>()
+        };
+
+        let days = if structured.star_dom {
+            vec!["*".to_string()]
+        } else {
+            let mut days = join(&structured.days_of_month);
+            if structured.last_day_of_month {
+                days.push("L".to_string());
+            }
+            days.extend(
+                structured
+                    .last_day_offsets
+                    .iter()
+                    .map(|offset| format!("L-{}", offset)),
+            );
+            days.extend(
+                structured
+                    .closest_weekdays
+                    .iter()
+                    .map(|day| format!("{}W", day)),
+            );
+            days
+        };
+
+        let days_of_week = if structured.star_dow {
+            vec!["*".to_string()]
+        } else {
+            let mut days_of_week = join(&structured.days_of_week);
+            days_of_week.extend(
+                structured
+                    .nth_days_of_week
+                    .iter()
+                    .map(|(weekday, nth)| format!("{}#{}", weekday, nth)),
+            );
+            days_of_week.extend(
+                structured
+                    .last_days_of_week
+                    .iter()
+                    .map(|weekday| format!("{}#L", weekday)),
+            );
+            days_of_week
+        };
+
+        // Only day of month and day of week treat a wildcard differently from a full list
+        let join_or_star = |values: &[u8], min: u8, max: u8| {
+            if values.iter().copied().eq(min..=max) {
+                vec!["*".to_string()]
+            } else {
+                join(values)
+            }
+        };
+
+        let fields = [
+            ("seconds", join_or_star(&structured.seconds, 0, 59)),
+            ("minutes", join_or_star(&structured.minutes, 0, 59)),
+            ("hours", join_or_star(&structured.hours, 0, 23)),
+            ("days of month", days),
+            ("months", join_or_star(&structured.months, 1, 12)),
+            ("days of week", days_of_week),
+        ];
+        let mut parts = Vec::with_capacity(fields.len());
+        for (name, values) in fields {
+            if values.is_empty() {
+                return Err(CronError::InvalidPattern(format!(
+                    "Structured pattern has no {}.",
+                    name
+                )));
+            }
+            parts.push(values.join(","));
+        }
+
+        let mut cron = Cron::new(&parts.join(" "));
+        cron.with_seconds_required();
+        if structured.dom_and_dow {
+            cron.with_dom_and_dow();
+        }
+        cron.parse()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for CronStructured {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("CronStructured", FIELD_NAMES.len())?;
+        state.serialize_field("seconds", &self.seconds)?;
+        state.serialize_field("minutes", &self.minutes)?;
+        state.serialize_field("hours", &self.hours)?;
+        state.serialize_field("days_of_month", &self.days_of_month)?;
+        state.serialize_field("last_day_of_month", &self.last_day_of_month)?;
+        state.serialize_field("last_day_offsets", &self.last_day_offsets)?;
+        state.serialize_field("closest_weekdays", &self.closest_weekdays)?;
+        state.serialize_field("months", &self.months)?;
+        state.serialize_field("days_of_week", &self.days_of_week)?;
+        state.serialize_field("nth_days_of_week", &self.nth_days_of_week)?;
+        state.serialize_field("last_days_of_week", &self.last_days_of_week)?;
+        state.serialize_field("dom_and_dow", &self.dom_and_dow)?;
+        state.serialize_field("star_dom", &self.star_dom)?;
+        state.serialize_field("star_dow", &self.star_dow)?;
+        state.end()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for CronStructured {
+    fn deserialize<D>(deserializer: D) -> Result<CronStructured, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct CronStructuredVisitor;
+
+        impl<'de> Visitor<'de> for CronStructuredVisitor {
+            type Value = CronStructured;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("a structured cron pattern")
+            }
+
+            // Fields that are left out keep their default, which is empty or false
+            fn visit_map<A>(self, mut map: A) -> Result<CronStructured, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut structured = CronStructured::default();
+                while let Some(key) = map.next_key::<String>()? {
+                    match key.as_str() {
+                        "seconds" => structured.seconds = map.next_value()?,
+                        "minutes" => structured.minutes = map.next_value()?,
+                        "hours" => structured.hours = map.next_value()?,
+                        "days_of_month" => structured.days_of_month = map.next_value()?,
+                        "last_day_of_month" => structured.last_day_of_month = map.next_value()?,
+                        "last_day_offsets" => structured.last_day_offsets = map.next_value()?,
+                        "closest_weekdays" => structured.closest_weekdays = map.next_value()?,
+                        "months" => structured.months = map.next_value()?,
+                        "days_of_week" => structured.days_of_week = map.next_value()?,
+                        "nth_days_of_week" => structured.nth_days_of_week = map.next_value()?,
+                        "last_days_of_week" => structured.last_days_of_week = map.next_value()?,
+                        "dom_and_dow" => structured.dom_and_dow = map.next_value()?,
+                        "star_dom" => structured.star_dom = map.next_value()?,
+                        "star_dow" => structured.star_dow = map.next_value()?,
+                        unknown => return Err(de::Error::unknown_field(unknown, &FIELD_NAMES)),
+                    }
+                }
+                Ok(structured)
+            }
+        }
+
+        deserializer.deserialize_struct("CronStructured", &FIELD_NAMES, CronStructuredVisitor)
+    }
+}
+
+/// Serializes a `Cron` in its structured form, for use with
+/// `#[serde(with = "croner::structured")]`.
+#[cfg(feature = "serde")]
+pub fn serialize<S>(cron: &Cron, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    CronStructured::from(cron).serialize(serializer)
+}
+
+/// Deserializes a `Cron` from its structured form, for use with
+/// `#[serde(with = "croner::structured")]`.
+#[cfg(feature = "serde")]
+pub fn deserialize<'de, D>(deserializer: D) -> Result<Cron, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let structured = CronStructured::deserialize(deserializer)?;
+    Cron::try_from(structured).map_err(de::Error::custom)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{TimeZone, Utc};
+
+    // Converts a pattern to its structure and back, checking the rebuilt schedule is equal
+    fn round_trip(cron: &Cron) -> Result<Cron, CronError> {
+        let rebuilt = Cron::try_from(CronStructured::from(cron))?;
+        let start = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        let expected: Vec<_> = cron.clone().iter_from(start).take(20).collect();
+        let found: Vec<_> = rebuilt.clone().iter_from(start).take(20).collect();
+        assert_eq!(found, expected, "{}", cron.pattern.as_str());
+        Ok(rebuilt)
+    }
+
+    #[test]
+    fn test_structured_fields() -> Result<(), CronError> {
+        let cron = Cron::new("*/20 5 8-10 1,6 *").parse()?;
+        let structured = CronStructured::from(&cron);
+        assert_eq!(structured.seconds, vec![0]);
+        assert_eq!(structured.minutes, vec![0, 20, 40]);
+        assert_eq!(structured.hours, vec![5]);
+        assert_eq!(structured.days_of_month, vec![8, 9, 10]);
+        assert!(!structured.last_day_of_month);
+        assert_eq!(structured.last_day_offsets, Vec::<u8>::new());
+        assert_eq!(structured.months, vec![1, 6]);
+        assert_eq!(structured.nth_days_of_week, vec![]);
+        assert!(!structured.star_dom && structured.star_dow);
+
+        let cron = Cron::new("0 0 L-2,15W,L * 5#2,1#L").parse()?;
+        let structured = CronStructured::from(&cron);
+        assert!(structured.last_day_of_month);
+        assert_eq!(structured.last_day_offsets, vec![2]);
+        assert_eq!(structured.closest_weekdays, vec![15]);
+        assert_eq!(structured.nth_days_of_week, vec![(5, 2)]);
+        assert_eq!(structured.last_days_of_week, vec![1]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_cron_fields() -> Result<(), CronError> {
+        let cron = Cron::new("0 0 L-2,15W * 5#2,1L").parse()?;
+        assert_eq!(cron.fields(), CronStructured::from(&cron));
+
+        // Weekdays are reported from 0 (Sunday) in alternative weekday mode as well
+        let cron = Cron::new("0 0 0 ? * 1,6#3,7L")
+            .with_alternative_weekdays()
+            .with_seconds_required()
+            .parse()?;
+        let fields = cron.fields();
+        assert_eq!(fields.days_of_week, vec![0]);
+        assert_eq!(fields.nth_days_of_week, vec![(5, 3)]);
+        assert_eq!(fields.last_days_of_week, vec![6]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_structured_round_trip() -> Result<(), CronError> {
+        for pattern in [
+            "0 9 * * MON-FRI",
+            "*/15 * * * *",
+            "0 0 L-2,15W,L * *",
+            "0 0 * * 5#2,1#L",
+            "0 0 1 * MON",
+            "0 0 1-31 * MON",
+            "@weekly",
+        ] {
+            round_trip(&Cron::new(pattern).parse()?)?;
+        }
+
+        let rebuilt = round_trip(&Cron::new("0 0 13 * FRI").with_dom_and_dow().parse()?)?;
+        assert!(rebuilt.pattern.dom_and_dow);
+        let rebuilt = round_trip(&Cron::new("0 0 * * 1").with_alternative_weekdays().parse()?)?;
+        assert_eq!(CronStructured::from(&rebuilt).days_of_week, vec![0]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_structured_rejects_empty_fields() {
+        let structured = CronStructured::default();
+        assert!(matches!(
+            Cron::try_from(structured),
+            Err(CronError::InvalidPattern(_))
+        ));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_structured_serde_tokens() {
+        use serde_test::{assert_de_tokens_error, assert_tokens, Token};
+
+        let cron = Cron::new("0 12 * 1 *").parse().unwrap();
+        let structured = CronStructured::from(&cron);
+        let list = |values: &[u8]| {
+            let mut tokens = vec![Token::Seq {
+                len: Some(values.len()),
+            }];
+            tokens.extend(values.iter().map(|value| Token::U8(*value)));
+            tokens.push(Token::SeqEnd);
+            tokens
+        };
+        let mut tokens = vec![Token::Struct {
+            name: "CronStructured",
+            len: 14,
+        }];
+        let all_days: Vec<u8> = (1..=31).collect();
+        let all_weekdays: Vec<u8> = (0..=6).collect();
+        for (name, values) in [
+            ("seconds", vec![0]),
+            ("minutes", vec![0]),
+            ("hours", vec![12]),
+            ("days_of_month", all_days),
+        ] {
+            tokens.push(Token::Str(name));
+            tokens.extend(list(&values));
+        }
+        tokens.extend([Token::Str("last_day_of_month"), Token::Bool(false)]);
+        for (name, values) in [
+            ("last_day_offsets", vec![]),
+            ("closest_weekdays", vec![]),
+            ("months", vec![1]),
+            ("days_of_week", all_weekdays),
+            ("nth_days_of_week", vec![]),
+            ("last_days_of_week", vec![]),
+        ] {
+            tokens.push(Token::Str(name));
+            tokens.extend(list(&values));
+        }
+        for (name, value) in [
+            ("dom_and_dow", false),
+            ("star_dom", true),
+            ("star_dow", true),
+        ] {
+            tokens.extend([Token::Str(name), Token::Bool(value)]);
+        }
+        tokens.push(Token::StructEnd);
+        assert_tokens(&structured, &tokens);
+
+        assert_de_tokens_error::<CronStructured>(
+            &[
+                Token::Struct {
+                    name: "CronStructured",
+                    len: 1,
+                },
+                Token::Str("years"),
+            ],
+            "unknown field `years`, expected one of `seconds`, `minutes`, `hours`, \
+             `days_of_month`, `last_day_of_month`, `last_day_offsets`, `closest_weekdays`, \
+             `months`, `days_of_week`, `nth_days_of_week`, `last_days_of_week`, `dom_and_dow`, \
+             `star_dom`, `star_dow`",
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_structured_with_module() {
+        use serde::de::{value, IntoDeserializer};
+        use std::collections::HashMap;
+
+        let fields: HashMap<&str, Vec<u8>> = [
+            ("seconds", vec![0]),
+            ("minutes", vec![30]),
+            ("hours", vec![6]),
+            ("days_of_month", vec![1]),
+            ("months", vec![1, 7]),
+            ("days_of_week", vec![1]),
+        ]
+        .into_iter()
+        .collect();
+        let deserializer: value::MapDeserializer<_, value::Error> = fields.into_deserializer();
+        let cron = deserialize(deserializer).unwrap();
+        assert_eq!(cron.pattern.to_string(), "0 30 6 1 1,7 1");
+    }
+}
@@ -0,0 +1,404 @@
+use crate::component::{CronComponent, ALL_BIT, CLOSEST_WEEKDAY_BIT, LAST_BIT, NTH_ALL};
+use crate::errors::CronError;
+use crate::pattern::CronPattern;
+use crate::Cron;
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+
+const WEEKDAY_NAMES: [&str; 7] = ["Sun", "Mon", "Tue", "Wed", "Thu", "Fri", "Sat"];
+const WEEKDAY_FULL_NAMES: [&str; 7] = [
+    "Sunday",
+    "Monday",
+    "Tuesday",
+    "Wednesday",
+    "Thursday",
+    "Friday",
+    "Saturday",
+];
+
+// Shorthands accepted by systemd, with their normalized calendar event
+const SHORTHANDS: [(&str, &str); 9] = [
+    ("minutely", "*-*-* *:*:00"),
+    ("hourly", "*-*-* *:00:00"),
+    ("daily", "*-*-* 00:00:00"),
+    ("weekly", "Mon *-*-* 00:00:00"),
+    ("monthly", "*-*-01 00:00:00"),
+    ("quarterly", "*-01,04,07,10-01 00:00:00"),
+    ("semiannually", "*-01,07-01 00:00:00"),
+    ("yearly", "*-01-01 00:00:00"),
+    ("annually", "*-01-01 00:00:00"),
+];
+
+// Translates a parsed pattern into the calendar event syntax of systemd timers (OnCalendar=),
+// like "Mon..Fri *-*-* 09:00:00". Features without a systemd equivalent result in an error.
+pub fn to_oncalendar(pattern: &CronPattern) -> Result<String, CronError> {
+    let incompatible = |reason: &str| {
+        Err(CronError::InvalidPattern(format!(
+            "Pattern can not be expressed as a systemd calendar event: {}",
+            reason
+        )))
+    };
+
+    let days = &pattern.days;
+    let days_of_week = &pattern.days_of_week;
+    if !days.get_set_values(CLOSEST_WEEKDAY_BIT).is_empty() {
+        return incompatible("closest weekday (W) is not supported.");
+    }
+    if !days_of_week.get_set_values(NTH_ALL | LAST_BIT).is_empty() {
+        return incompatible("nth and last weekday of month (# and L) are not supported.");
+    }
+
+    // systemd always requires both the date and the weekday to match, while cron matches
+    // either of them unless told otherwise
+    let weekdays = days_of_week.get_set_values(ALL_BIT);
+    let all_weekdays = (0..=6).all(|weekday| weekdays.contains(&weekday));
+    let dom_restricted = !pattern.star_dom();
+    let dow_restricted = !pattern.star_dow() && !all_weekdays;
+    if dom_restricted && dow_restricted && !pattern.dom_and_dow {
+        return incompatible("day of month and day of week would have to match together.");
+    }
+
+    // Day of month, either regular days or a single offset from the end of the month
+    let last_offsets = days.get_set_values(LAST_BIT);
+    let regular_days = days.get_set_values(ALL_BIT);
+    let day_part = match (
+        days.is_feature_enabled(LAST_BIT),
+        last_offsets.as_slice(),
+        regular_days.is_empty(),
+    ) {
+        (false, [], _) if !dom_restricted => "-*".to_string(),
+        (false, [], false) => format!("-{}", format_values(days, &regular_days, 2)),
+        (true, [], true) => "~01".to_string(),
+        (false, [offset], true) => format!("~{:02}", offset + 1),
+        _ => {
+            return incompatible("last day of month (L) can not be combined with other days.");
+        }
+    };
+
+    let weekday_part = if dow_restricted {
+        format!("{} ", format_weekdays(&weekdays))
+    } else {
+        String::new()
+    };
+
+    Ok(format!(
+        "{}*-{}{} {}:{}:{}",
+        weekday_part,
+        format_component(&pattern.months, 2),
+        day_part,
+        format_component(&pattern.hours, 2),
+        format_component(&pattern.minutes, 2),
+        format_component(&pattern.seconds, 2),
+    ))
+}
+
+// Translates a calendar event of systemd timers (OnCalendar=), like "Mon..Fri 09:00", into
+// a parsed Cron. Explicit years, sub-second precision and time zones have no cron equivalent.
+pub fn from_oncalendar(oncalendar: &str) -> Result<Cron, CronError> {
+    let unsupported = |reason: &str| {
+        Err(CronError::InvalidPattern(format!(
+            "Calendar event '{}' can not be expressed as a cron pattern: {}",
+            oncalendar, reason
+        )))
+    };
+
+    let trimmed = oncalendar.trim();
+    if let Some((_, expanded)) = SHORTHANDS
+        .iter()
+        .find(|(shorthand, _)| shorthand.eq_ignore_ascii_case(trimmed))
+    {
+        return from_oncalendar(expanded);
+    }
+
+    let mut weekdays = "*".to_string();
+    let (mut days, mut months) = ("*".to_string(), "*".to_string());
+    let (mut hours, mut minutes, mut seconds) = ("0", "0", "0");
+    for (index, token) in trimmed.split_whitespace().enumerate() {
+        if token.contains(':') {
+            let parts: Vec<&str> = token.split(':').collect();
+            match parts.as_slice() {
+                [h, m] => (hours, minutes) = (h, m),
+                [h, m, s] => (hours, minutes, seconds) = (h, m, s),
+                _ => return unsupported("invalid time."),
+            }
+        } else if token.contains('-') || token.contains('~') {
+            let (date, last_offset) = match token.split_once('~') {
+                Some((date, offset)) => (date, Some(offset)),
+                None => (token, None),
+            };
+            let mut parts: Vec<&str> = date.split('-').collect();
+            if last_offset.is_some() {
+                parts.push("*");
+            }
+            let (year, month, day) = match parts.as_slice() {
+                [year, month, day] => (*year, *month, *day),
+                [month, day] => ("*", *month, *day),
+                _ => return unsupported("invalid date."),
+            };
+            if year != "*" {
+                return unsupported("explicit years are not supported.");
+            }
+            months = convert_values(month, 12)?;
+            days = match last_offset.map(str::parse::<u8>) {
+                None => convert_values(day, 31)?,
+                Some(Ok(1)) => "L".to_string(),
+                Some(Ok(offset @ 2..=28)) => format!("L-{}", offset - 1),
+                Some(_) => return unsupported("unsupported offset from the end of the month."),
+            };
+        } else if index == 0 && token.starts_with(|c: char| c.is_ascii_alphabetic()) {
+            weekdays = convert_weekdays(token)?;
+        } else {
+            return unsupported("time zones and other specifiers are not supported.");
+        }
+    }
+
+    // systemd requires both the date and the weekday to match
+    let mut cron = Cron::new(&format!(
+        "{} {} {} {} {} {}",
+        convert_values(seconds, 59)?,
+        convert_values(minutes, 59)?,
+        convert_values(hours, 23)?,
+        days,
+        months,
+        weekdays
+    ));
+    cron.with_seconds_required();
+    if days != "*" && weekdays != "*" {
+        cron.with_dom_and_dow();
+    }
+    cron.parse()
+}
+
+// Converts systemd values like "01,10..12" and "00/15" into cron syntax like "1,10-12" and
+// "0-59/15", where max is the last value of the field
+fn convert_values(values: &str, max: u8) -> Result<String, CronError> {
+    let converted = values.replace("..", "-");
+    if converted.contains('.') {
+        return Err(CronError::InvalidPattern(format!(
+            "Value '{}' uses sub-second precision, which cron does not support.",
+            values
+        )));
+    }
+
+    // Drop leading zeros from numbers, "00/15" becomes "0/15"
+    let mut normalized = String::with_capacity(converted.len());
+    let mut leading = true;
+    for (index, c) in converted.char_indices() {
+        if c.is_ascii_digit() {
+            let last_digit = !converted[index + 1..].starts_with(|n: char| n.is_ascii_digit());
+            if leading && c == '0' && !last_digit {
+                continue;
+            }
+            leading = false;
+        } else {
+            leading = true;
+        }
+        normalized.push(c);
+    }
+
+    // A repetition from a single value runs up to the end of the field, "0/15" becomes "0-59/15"
+    let entries: Vec<String> = normalized
+        .split(',')
+        .map(|entry| match entry.split_once('/') {
+            Some((start, step)) if start != "*" && !start.contains('-') => {
+                format!("{}-{}/{}", start, max, step)
+            }
+            _ => entry.to_string(),
+        })
+        .collect();
+    Ok(entries.join(","))
+}
+
+// Converts systemd weekdays like "Mon..Fri,Sun" into cron syntax like "MON-FRI,SUN"
+fn convert_weekdays(weekdays: &str) -> Result<String, CronError> {
+    let convert_name = |name: &str| {
+        WEEKDAY_NAMES
+            .iter()
+            .zip(WEEKDAY_FULL_NAMES)
+            .find(|(short, full)| {
+                short.eq_ignore_ascii_case(name) || full.eq_ignore_ascii_case(name)
+            })
+            .map(|(short, _)| short.to_uppercase())
+            .ok_or_else(|| CronError::InvalidPattern(format!("Invalid weekday '{}'.", name)))
+    };
+    weekdays
+        .split(',')
+        .map(|entry| match entry.split_once("..") {
+            Some((start, end)) => Ok(format!("{}-{}", convert_name(start)?, convert_name(end)?)),
+            None => convert_name(entry),
+        })
+        .collect::<Result<Vec<_>, CronError>>()
+        .map(|entries| entries.join(","))
+}
+
+// Formats the values of a component, "*" if every value is set
+fn format_component(component: &CronComponent, width: usize) -> String {
+    let values = component.get_set_values(ALL_BIT);
+    if values.len() == (component.max - component.min + 1) as usize {
+        "*".to_string()
+    } else {
+        format_values(component, &values, width)
+    }
+}
+
+// Formats values as a repetition like "00/15" if they step evenly up to the end of the
+// component, otherwise as a list of single values and ranges like "01,10..12"
+fn format_values(component: &CronComponent, values: &[u8], width: usize) -> String {
+    if let [first, second, third, ..] = values {
+        let step = second - first;
+        let stepping = (*first..=component.max).step_by(step as usize);
+        if third - second == step && stepping.eq(values.iter().copied()) {
+            return format!("{:0width$}/{}", first, step, width = width);
+        }
+    }
+    group_runs(values)
+        .iter()
+        .map(|(start, end)| match end - start {
+            0 => format!("{:0width$}", start, width = width),
+            1 => format!("{:0width$},{:0width$}", start, end, width = width),
+            _ => format!("{:0width$}..{:0width$}", start, end, width = width),
+        })
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+// Formats weekdays by name, like "Mon..Fri" or "Sat,Sun"
+fn format_weekdays(weekdays: &[u8]) -> String {
+    // Rotate Sunday to the end, so that ranges like Fri..Sun are kept together
+    let mut monday_first: Vec<u8> = weekdays.iter().map(|d| (d + 6) % 7).collect();
+    monday_first.sort_unstable();
+    let name = |monday_first: u8| WEEKDAY_NAMES[((monday_first + 1) % 7) as usize];
+    group_runs(&monday_first)
+        .iter()
+        .map(|(start, end)| match end - start {
+            0 => name(*start).to_string(),
+            1 => format!("{},{}", name(*start), name(*end)),
+            _ => format!("{}..{}", name(*start), name(*end)),
+        })
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+// Groups sorted values into runs of consecutive values
+fn group_runs(values: &[u8]) -> Vec<(u8, u8)> {
+    let mut runs: Vec<(u8, u8)> = Vec::new();
+    for value in values {
+        match runs.last_mut() {
+            Some((_, end)) if *end + 1 == *value => *end = *value,
+            _ => runs.push((*value, *value)),
+        }
+    }
+    runs
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn oncalendar(pattern: &str) -> Result<String, CronError> {
+        to_oncalendar(&CronPattern::new(pattern).with_seconds_optional().parse()?)
+    }
+
+    #[test]
+    fn test_to_oncalendar() -> Result<(), CronError> {
+        assert_eq!(oncalendar("0 9 * * MON-FRI")?, "Mon..Fri *-*-* 09:00:00");
+        assert_eq!(oncalendar("* * * * *")?, "*-*-* *:*:00");
+        assert_eq!(oncalendar("*/15 * * * *")?, "*-*-* *:00/15:00");
+        assert_eq!(oncalendar("30 0 12 1,15 * *")?, "*-*-01,15 12:00:30");
+        assert_eq!(oncalendar("0 0 1-3,10 */3 *")?, "*-01/3-01..03,10 00:00:00");
+        assert_eq!(oncalendar("0 0 L 2 *")?, "*-02~01 00:00:00");
+        assert_eq!(oncalendar("0 0 L-2 * *")?, "*-*~03 00:00:00");
+        assert_eq!(oncalendar("0 18 * * FRI-SUN")?, "Fri..Sun *-*-* 18:00:00");
+        assert_eq!(oncalendar("0 18 * * 0,6")?, "Sat,Sun *-*-* 18:00:00");
+        Ok(())
+    }
+
+    #[test]
+    fn test_to_oncalendar_dom_and_dow() -> Result<(), CronError> {
+        // Cron matches either the day of month or the day of week by default
+        assert!(oncalendar("0 0 13 * FRI").is_err());
+
+        let pattern = CronPattern::new("0 0 13 * FRI")
+            .with_dom_and_dow()
+            .parse()?;
+        assert_eq!(to_oncalendar(&pattern)?, "Fri *-*-13 00:00:00");
+        Ok(())
+    }
+
+    #[test]
+    fn test_from_oncalendar() -> Result<(), CronError> {
+        let equivalent = |oncalendar: &str, pattern: &str| -> Result<(), CronError> {
+            let cron = from_oncalendar(oncalendar)?;
+            let expected = Cron::new(pattern).with_seconds_optional().parse()?;
+            assert_eq!(
+                cron.pattern.as_str(),
+                expected.pattern.as_str(),
+                "{oncalendar}"
+            );
+            Ok(())
+        };
+        equivalent("Mon..Fri 09:00", "0 0 9 * * 1-5")?;
+        equivalent("Saturday,Sun *-*-* 18:30:15", "15 30 18 * * 6,0")?;
+        equivalent("*:00/15", "0 0-59/15 * * * *")?;
+        equivalent("*-*-01 00:00:00", "0 0 0 1 * *")?;
+        equivalent("*-02~01 12:00", "0 0 12 L 2 *")?;
+        equivalent("*-*~03", "0 0 0 L-2 * *")?;
+        equivalent("01,07-01..03 08..17:00", "0 0 8-17 1-3 1,7 *")?;
+        equivalent("daily", "0 0 0 * * *")?;
+        equivalent("weekly", "0 0 0 * * 1")?;
+
+        // Both the date and the weekday have to match
+        assert!(from_oncalendar("Fri *-*-13")?.pattern.dom_and_dow);
+        Ok(())
+    }
+
+    #[test]
+    fn test_from_oncalendar_unsupported() {
+        for oncalendar in [
+            "2024-01-01 00:00:00",
+            "*-*-* 00:00:00.5",
+            "*-*-* 00:00:00 UTC",
+            "Someday 00:00",
+            "*-*~40",
+        ] {
+            assert!(
+                matches!(
+                    from_oncalendar(oncalendar),
+                    Err(CronError::InvalidPattern(_))
+                ),
+                "{oncalendar} should be rejected"
+            );
+        }
+    }
+
+    #[test]
+    fn test_oncalendar_round_trip() -> Result<(), CronError> {
+        for pattern in [
+            "0 9 * * MON-FRI",
+            "*/15 * * * *",
+            "0 0 L 2 *",
+            "0 12 1,15 * *",
+        ] {
+            let cron = Cron::new(pattern).parse()?;
+            let round_trip = from_oncalendar(&to_oncalendar(&cron.pattern)?)?;
+            assert_eq!(
+                to_oncalendar(&round_trip.pattern)?,
+                to_oncalendar(&cron.pattern)?
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_to_oncalendar_incompatible() {
+        for pattern in ["0 0 * * 5#2", "0 0 * * 5L", "0 0 15W * *", "0 0 L,15 * *"] {
+            assert!(
+                matches!(oncalendar(pattern), Err(CronError::InvalidPattern(_))),
+                "{pattern} should be rejected"
+            );
+        }
+    }
+}